@@ -0,0 +1,300 @@
+use aoc_core::{ParseError, Solution};
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead},
+};
+
+/// Totals of the three elves carrying the most, most first: part 1 wants the first of them.
+fn top_3(elves: &[Elf]) -> Vec<u64> {
+    top_k(elves.iter().map(Elf::total), 3)
+}
+
+fn task_1(elves: &[Elf]) -> u64 {
+    top_3(elves).first().copied().unwrap_or(0)
+}
+
+fn task_2(elves: &[Elf]) -> u64 {
+    top_3(elves).into_iter().sum()
+}
+
+/// The food an elf carries, one item per line of its group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Elf {
+    /// 0-based position of the elf in the input.
+    pub index: usize,
+    pub calories: Vec<u64>,
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.calories.iter().sum()
+    }
+}
+
+/// The elves read one group of lines at a time, groups are separated by blank lines.
+pub struct Elves<R> {
+    lines: io::Lines<R>,
+    line_no: usize,
+    n_elves: usize,
+}
+
+/// Reads the elves from `reader` lazily, without holding more than one of them in memory.
+pub fn elves<R: BufRead>(reader: R) -> Elves<R> {
+    Elves {
+        lines: reader.lines(),
+        line_no: 0,
+        n_elves: 0,
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut calories = Vec::new();
+        for line in self.lines.by_ref() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err.into())),
+            };
+            self.line_no += 1;
+
+            let item = line.trim();
+            if item.is_empty() {
+                if calories.is_empty() {
+                    continue; // runs of blank lines separate a single pair of elves
+                }
+                break;
+            }
+            match item.parse() {
+                Ok(value) => calories.push(value),
+                Err(_) => {
+                    let err = ParseError::at_token(&line, item, "number of calories");
+                    return Some(Err(err.at_line(self.line_no, &line).into()));
+                }
+            }
+        }
+
+        if calories.is_empty() {
+            return None;
+        }
+        self.n_elves += 1;
+        Some(Ok(Elf {
+            index: self.n_elves - 1,
+            calories,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "{}", err),
+            ReadError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        ReadError::Parse(err)
+    }
+}
+
+/// The `k` largest of the items seen so far, found with a min-heap of at most `k` of them.
+pub struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.heap.push(Reverse(item));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The items kept, largest first.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // Sorting the reversed items ascending puts the largest ones first
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(item)| item)
+            .collect()
+    }
+}
+
+/// The `k` largest items, largest first.
+pub fn top_k<T: Ord>(items: impl IntoIterator<Item = T>, k: usize) -> Vec<T> {
+    let mut top = TopK::new(k);
+    items.into_iter().for_each(|item| top.push(item));
+    top.into_sorted_vec()
+}
+
+/// Totals carried by the elves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub min: u64,
+    pub max: u64,
+    /// Mean of the two middle totals if the number of elves is even.
+    pub median: f64,
+    /// Indices and totals of the elves carrying the most, most first (the first elf of
+    /// those carrying the same).
+    pub top: Vec<(usize, u64)>,
+}
+
+impl Stats {
+    /// Gathers the statistics with `k` top elves in a single pass, `None` if there are no
+    /// elves.
+    pub fn collect<'a>(elves: impl IntoIterator<Item = &'a Elf>, k: usize) -> Option<Self> {
+        let mut totals = Vec::new();
+        let mut top = TopK::new(k);
+        for elf in elves {
+            let total = elf.total();
+            totals.push(total);
+            top.push((total, Reverse(elf.index)));
+        }
+        if totals.is_empty() {
+            return None;
+        }
+
+        totals.sort_unstable();
+        let count = totals.len();
+        let median = match count % 2 {
+            1 => totals[count / 2] as f64,
+            _ => (totals[count / 2 - 1] + totals[count / 2]) as f64 / 2.0,
+        };
+        Some(Self {
+            count,
+            min: totals[0],
+            max: totals[count - 1],
+            median,
+            top: top
+                .into_sorted_vec()
+                .into_iter()
+                .map(|(total, Reverse(index))| (index, total))
+                .collect(),
+        })
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Elf>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        elves(content.as_bytes())
+            .map(|elf| {
+                elf.map_err(|err| match err {
+                    ReadError::Parse(err) => err,
+                    ReadError::Io(err) => unreachable!("reading from memory failed: {}", err),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(elves: &Self::Input) -> impl Display {
+        task_1(elves)
+    }
+
+    fn part2(elves: &Self::Input) -> impl Display {
+        task_2(elves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input).to_string(), "24000");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input).to_string(), "45000");
+    }
+
+    #[test]
+    fn stats_of_example() {
+        // A tiny buffer makes the reader refill in the middle of the lines
+        let reader = BufReader::with_capacity(3, EXAMPLE.as_bytes());
+        let elves: Vec<Elf> = elves(reader).collect::<Result<_, _>>().unwrap();
+
+        let stats = Stats::collect(&elves, 3).unwrap();
+        assert_eq!(stats.count, 5);
+        assert_eq!((stats.min, stats.max), (4000, 24000));
+        assert_eq!(stats.median, 10000.0);
+        assert_eq!(stats.top, [(3, 24000), (2, 11000), (4, 10000)]);
+
+        assert_eq!(Stats::collect(&elves[..2], 3).unwrap().median, 5000.0);
+        assert_eq!(Stats::collect(&[], 3), None);
+    }
+
+    #[test]
+    fn top_k_keeps_the_largest() {
+        assert_eq!(top_k([5, 1, 9, 3, 9, 7], 3), [9, 9, 7]);
+        assert_eq!(top_k([2, 1], 3), [2, 1]);
+        assert_eq!(top_k([2, 1], 0), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn rejects_malformed_calories() {
+        let err = Day1::parse("1000\n\n20x0").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    fn crlf_example() {
+        let crlf = EXAMPLE.replace('\n', "\r\n") + "\r\n\r\n";
+        assert_eq!(Day1::parse(&crlf).unwrap(), Day1::parse(EXAMPLE).unwrap());
+    }
+}
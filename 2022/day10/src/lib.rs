@@ -0,0 +1,442 @@
+use aoc_core::{parse_lines, ParseError, Simulation, Solution, Tokens, Visualize};
+use grid::{Grid, Pos};
+use std::fmt::Display;
+
+#[derive(Clone, Copy)]
+enum Op {
+    Addx(i32, i32),
+    Noop(i32),
+}
+
+impl Op {
+    fn parse(s: &str) -> Result<Op, ParseError> {
+        let mut tokens = Tokens::new(s);
+        let op = match tokens.word("command")? {
+            "noop" => Op::Noop(1),
+            "addx" => Op::Addx(tokens.number()?, 2),
+            cmd => return Err(tokens.error(cmd, "'noop' or 'addx'")),
+        };
+        tokens.finish()?;
+        Ok(op)
+    }
+}
+
+// Task 1
+
+fn task_1(program: &Program) -> i32 {
+    let mut cpu = Cpu::new();
+
+    let mut sig_strength = 0;
+    for &op in &program.ops {
+        let prev = (cpu.cycles_done + 20) / 40;
+        let prev_reg_x = cpu.reg_x;
+        cpu.dispatcher(op);
+        let next = (cpu.cycles_done + 20) / 40;
+        if next != prev {
+            sig_strength += prev_reg_x * (prev * 40 + 20)
+        }
+    }
+
+    sig_strength
+}
+
+struct Cpu {
+    reg_x: i32,
+    cycles_done: i32,
+}
+
+impl Cpu {
+    fn new() -> Cpu {
+        Cpu {
+            reg_x: 1,
+            cycles_done: 0,
+        }
+    }
+
+    fn dispatcher(&mut self, op: Op) {
+        match op {
+            Op::Addx(v, c) => self.addx(v, c),
+            Op::Noop(c) => self.noop(c),
+        }
+    }
+
+    fn addx(&mut self, value: i32, cycles: i32) {
+        self.make_cycles(cycles);
+        self.reg_x += value;
+    }
+
+    fn noop(&mut self, cycles: i32) {
+        self.make_cycles(cycles)
+    }
+
+    fn make_cycles(&mut self, cycles: i32) {
+        self.cycles_done += cycles;
+    }
+}
+
+// Task 2
+
+fn task_2(program: &Program) -> String {
+    let mut crt = Crt::new(program);
+    while crt.step() {}
+    crt.renderer.screen.to_string()
+}
+
+/// The screen being drawn by the program, one cycle (and pixel) at a time.
+struct Crt {
+    renderer: Renderer,
+    cpu: Cpu2,
+    cycle: usize,
+}
+
+impl Crt {
+    fn new(program: &Program) -> Self {
+        let mut cpu = Cpu2::new();
+        cpu.load(program.clone());
+        Self {
+            renderer: Renderer::new(CrtScreen::new(6, 40)),
+            cpu,
+            cycle: 0,
+        }
+    }
+}
+
+impl Simulation for Crt {
+    fn step(&mut self) -> bool {
+        let buffer = &self.renderer.screen.buffer;
+        if self.cycle == buffer.width() * buffer.height() {
+            return false;
+        }
+        self.renderer.do_cycle(self.cpu.reg.x);
+        self.cpu.do_cycle();
+        self.cycle += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        format!("cycle {}\n{}", self.cycle, self.renderer.screen)
+    }
+}
+
+struct CrtScreen {
+    buffer: Grid<char>,
+}
+
+impl CrtScreen {
+    fn new(height: usize, width: usize) -> Self {
+        Self {
+            buffer: Grid::new(width, height, '.'),
+        }
+    }
+}
+
+struct Renderer {
+    screen: CrtScreen,
+    cur_pos: usize,
+}
+
+impl Renderer {
+    fn new(screen: CrtScreen) -> Self {
+        Self { screen, cur_pos: 0 }
+    }
+
+    fn do_cycle(&mut self, sprite_pos: i32) {
+        let width = self.screen.buffer.width();
+        let p = Pos::new((self.cur_pos % width) as i32, (self.cur_pos / width) as i32);
+        if sprite_pos - 1 <= p.x && p.x <= sprite_pos + 1 {
+            self.screen.buffer[p] = '#';
+        }
+        self.cur_pos += 1;
+    }
+}
+
+impl Display for CrtScreen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.buffer.fmt(f)
+    }
+}
+
+struct Registers {
+    x: i32,
+    ip: usize, // instruction pointer (points to next instruction to execute)
+    icc: i32,  // instuction cycle counter
+}
+
+impl Registers {
+    fn new() -> Self {
+        Self {
+            x: 1,
+            ip: 0,
+            icc: 0,
+        }
+    }
+}
+
+struct Cpu2 {
+    reg: Registers,
+    program: Option<Program>,
+}
+
+impl Cpu2 {
+    fn new() -> Self {
+        Self {
+            reg: Registers::new(),
+            program: None,
+        }
+    }
+
+    fn load(&mut self, program: Program) {
+        self.program = Some(program);
+    }
+
+    fn do_cycle(&mut self) {
+        if let Some(program) = &self.program {
+            if let Some(op) = program.ops.get(self.reg.ip) {
+                match op {
+                    Op::Addx(v, c) => {
+                        self.reg.icc += 1;
+                        if self.reg.icc == *c {
+                            self.reg.icc = 0;
+                            self.reg.ip += 1;
+                            self.reg.x += v;
+                        }
+                    }
+                    Op::Noop(_) => {
+                        self.reg.icc = 0;
+                        self.reg.ip += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Program {
+    ops: Vec<Op>,
+}
+
+impl Program {
+    fn parse(text: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            ops: parse_lines(text, Op::parse)?,
+        })
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Program;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        Program::parse(content)
+    }
+
+    fn part1(program: &Self::Input) -> impl Display {
+        task_1(program)
+    }
+
+    fn part2(program: &Self::Input) -> impl Display {
+        task_2(program)
+    }
+}
+
+impl Visualize for Day10 {
+    fn simulation(program: &Self::Input, _part: u32) -> Box<dyn Simulation + '_> {
+        Box::new(Crt::new(program))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+    const SCREEN: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input).to_string(), "13140");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input).to_string().trim_end(), SCREEN);
+    }
+
+    #[test]
+    fn crt_draws_row_by_row() {
+        let program = Day10::parse(EXAMPLE).unwrap();
+        let mut crt = Crt::new(&program);
+        for _ in 0..45 {
+            crt.step();
+        }
+        let frame = crt.render();
+        let lines: Vec<&str> = frame.lines().collect();
+        assert_eq!(lines[0], "cycle 45");
+        assert_eq!(lines[1], SCREEN.lines().next().unwrap());
+        assert_eq!(lines[2], format!("###{}", ".".repeat(37)));
+        assert_eq!(lines[3], ".".repeat(40));
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day10>(EXAMPLE);
+    }
+}
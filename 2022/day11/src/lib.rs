@@ -0,0 +1,416 @@
+use aoc_core::{ParseError, Solution, Tokens};
+use std::{cmp::Reverse, collections::VecDeque, fmt::Display};
+use tracing::{debug, trace};
+
+fn task_1(monkeys: &[Monkey]) -> i32 {
+    let mut game = Game::new(monkeys.to_vec());
+    game.print();
+
+    for i in 0..20 {
+        trace!("Round {}:", i + 1);
+        game.play_round();
+        game.print();
+    }
+
+    game.print_inspects_info();
+
+    let mut counts = game.inspect_count;
+    counts.sort_by_key(|k| Reverse(*k));
+
+    counts[0] * counts[1]
+}
+
+fn task_2(monkeys: &[Monkey]) -> i64 {
+    let mut game = Game::new(monkeys.to_vec());
+
+    for i in 0..10000 {
+        game.play_round_2();
+
+        if (i + 1) % 100 == 0 {
+            trace!("== Round: {}", i + 1);
+        }
+        if (i + 1) % 1000 == 0 {
+            debug!("== After round {} ==", i + 1);
+            game.print_inspects_info();
+        }
+    }
+
+    let mut counts = game.inspect_count;
+    counts.sort_by_key(|k| Reverse(*k));
+
+    (counts[0] as i64) * (counts[1] as i64)
+}
+
+// Task 1
+struct Game {
+    monkeys: Vec<Monkey>,
+    inspect_count: Vec<i32>,
+}
+
+impl Game {
+    fn new(monkeys: Vec<Monkey>) -> Self {
+        let sz = monkeys.len();
+        Self {
+            monkeys,
+            inspect_count: vec![0; sz],
+        }
+    }
+
+    fn play_round(&mut self) {
+        for i in 0..self.monkeys.len() {
+            self.inspect_count[i] += self.monkeys[i].items.len() as i32;
+            debug!("Monkey {}:", i);
+            while let Some(item) = self.monkeys[i].items.pop_front() {
+                let monkey = &self.monkeys[i];
+                debug!("  Monkey inspects item with a worry level of {}", item);
+                let item = monkey.op.eval(item);
+                debug!("  Worry level has been changed to {}", item);
+                let item = item / 3;
+                debug!(
+                    "  Monkey gets bored with item. Worry level is reduced to {}",
+                    item
+                );
+                let nxt_monkey = monkey.test.select(item) as usize;
+                debug!(
+                    "  Item with worry level {} is thrown to monkey {}",
+                    item, nxt_monkey
+                );
+                self.monkeys[nxt_monkey].items.push_back(item);
+            }
+        }
+    }
+
+    fn play_round_2(&mut self) {
+        let safe_divisor: i64 = self.monkeys.iter().map(|m| m.test.divisor).product();
+
+        for i in 0..self.monkeys.len() {
+            self.inspect_count[i] += self.monkeys[i].items.len() as i32;
+            debug!("Monkey {}:", i);
+            while let Some(item) = self.monkeys[i].items.pop_front() {
+                let monkey = &self.monkeys[i];
+                debug!("  Monkey inspects item with a worry level of {}", item);
+                let item = monkey.op.eval(item);
+                debug!("  Worry level has been changed to {}", item);
+                let item = item % safe_divisor;
+                debug!(
+                    "  Monkey gets bored with item. Worry level is reduced to {}",
+                    item
+                );
+                let nxt_monkey = monkey.test.select(item) as usize;
+                debug!(
+                    "  Item with worry level {} is thrown to monkey {}",
+                    item, nxt_monkey
+                );
+                self.monkeys[nxt_monkey].items.push_back(item);
+            }
+        }
+    }
+
+    fn print(&self) {
+        for (idx, monkey) in self.monkeys.iter().enumerate() {
+            let items: Vec<String> = monkey.items.iter().map(|item| item.to_string()).collect();
+            trace!("Monkey {}: {}", idx, items.join(", "));
+        }
+    }
+
+    fn print_inspects_info(&self) {
+        for (i, cnt) in self.inspect_count.iter().enumerate() {
+            debug!("Monkey {} inspected items {} time(s)", i, cnt);
+        }
+    }
+}
+
+// Common
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: VecDeque<i64>,
+    op: Op,
+    test: Test,
+}
+
+impl Monkey {
+    fn new(items: VecDeque<i64>, op: Op, test: Test) -> Self {
+        Self { items, op, test }
+    }
+}
+
+#[derive(Clone)]
+enum Operand {
+    Old,
+    Number(i64),
+}
+
+impl Operand {
+    fn eval(&self, old: i64) -> i64 {
+        match self {
+            Operand::Old => old,
+            Operand::Number(x) => *x,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Op {
+    Add(Operand, Operand),
+    Mult(Operand, Operand),
+}
+
+impl Op {
+    fn eval(&self, old: i64) -> i64 {
+        match self {
+            Op::Add(lhs, rhs) => lhs.eval(old) + rhs.eval(old),
+            Op::Mult(lhs, rhs) => lhs.eval(old) * rhs.eval(old),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Test {
+    divisor: i64,
+    true_monkey: i32,
+    false_monkey: i32,
+}
+
+impl Test {
+    fn select(&self, x: i64) -> i32 {
+        if x % self.divisor == 0 {
+            self.true_monkey
+        } else {
+            self.false_monkey
+        }
+    }
+}
+
+fn parse_monkeys(content: &str) -> Result<Vec<Monkey>, ParseError> {
+    let lines: Vec<&str> = content.trim_end_matches(['\r', '\n']).lines().collect();
+    let mut monkeys = Vec::new();
+
+    let mut idx = 0;
+    loop {
+        parse_line(&lines, idx, |line| parse_header(line, monkeys.len()))?;
+        let items = parse_line(&lines, idx + 1, parse_items)?;
+        let op = parse_line(&lines, idx + 2, parse_op)?;
+        let divisor = parse_line(&lines, idx + 3, parse_divisor)?;
+        let true_monkey = parse_line(&lines, idx + 4, |line| parse_throw(line, "true:"))?;
+        let false_monkey = parse_line(&lines, idx + 5, |line| parse_throw(line, "false:"))?;
+        let test = Test {
+            divisor,
+            true_monkey,
+            false_monkey,
+        };
+
+        monkeys.push(Monkey::new(items, op, test));
+
+        idx += 6;
+        if idx >= lines.len() {
+            break;
+        }
+        parse_line(&lines, idx, |line| Tokens::new(line).finish())?; // skip separator
+        idx += 1;
+    }
+
+    // Monkeys only throw to other monkeys, each of them on the 7 lines of its notes
+    let n_monkeys = monkeys.len();
+    for (i, monkey) in monkeys.iter().enumerate() {
+        let targets = [(4, monkey.test.true_monkey), (5, monkey.test.false_monkey)];
+        for (offset, target) in targets {
+            if usize::try_from(target).is_ok_and(|t| t < n_monkeys && t != i) {
+                continue;
+            }
+            let idx = 7 * i + offset;
+            let line = lines[idx];
+            let word = line.split_whitespace().last().unwrap();
+            let expected = format!("another monkey from 0 to {}", n_monkeys - 1);
+            return Err(ParseError::at_token(line, word, expected).at_line(idx + 1, line));
+        }
+    }
+
+    Ok(monkeys)
+}
+
+// Runs `f` on the line with the given index, a missing line is parsed as an empty one
+fn parse_line<T, F>(lines: &[&str], idx: usize, f: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&str) -> Result<T, ParseError>,
+{
+    let line = lines.get(idx).copied().unwrap_or("");
+    f(line).map_err(|err| err.at_line(idx + 1, line))
+}
+
+// Monkeys are numbered in order from 0
+fn parse_header(line: &str, n: usize) -> Result<(), ParseError> {
+    let mut tokens = Tokens::new(line);
+    tokens.literal("Monkey")?;
+    tokens.parse_with(&format!("'{}:'", n), |word| {
+        (word.strip_suffix(':')?.parse::<usize>().ok()? == n).then_some(())
+    })?;
+    tokens.finish()
+}
+
+fn parse_items(line: &str) -> Result<VecDeque<i64>, ParseError> {
+    let mut tokens = Tokens::new(line);
+    tokens.literal("Starting")?;
+    tokens.literal("items:")?;
+
+    tokens
+        .rest()
+        .map(|item| {
+            let number = item.strip_suffix(',').unwrap_or(item);
+            number
+                .parse::<i64>()
+                .map_err(|_| ParseError::at_token(line, item, "number"))
+        })
+        .collect()
+}
+
+fn parse_op(line: &str) -> Result<Op, ParseError> {
+    let mut tokens = Tokens::new(line);
+    tokens.literal("Operation:")?;
+    tokens.literal("new")?;
+    tokens.literal("=")?;
+
+    let lhs = tokens.parse_with("'old' or number", parse_operand)?;
+    let op = tokens.parse_with("'*' or '+'", |word| {
+        matches!(word, "*" | "+").then_some(word)
+    })?;
+    let rhs = tokens.parse_with("'old' or number", parse_operand)?;
+    tokens.finish()?;
+
+    match op {
+        "*" => Ok(Op::Mult(lhs, rhs)),
+        _ => Ok(Op::Add(lhs, rhs)),
+    }
+}
+
+fn parse_operand(word: &str) -> Option<Operand> {
+    if word == "old" {
+        Some(Operand::Old)
+    } else {
+        word.parse().ok().map(Operand::Number)
+    }
+}
+
+fn parse_divisor(line: &str) -> Result<i64, ParseError> {
+    let mut tokens = Tokens::new(line);
+    tokens.literal("Test:")?;
+    tokens.literal("divisible")?;
+    tokens.literal("by")?;
+    let divisor = tokens.parse_with("positive number", |word| {
+        word.parse::<i64>().ok().filter(|&divisor| divisor > 0)
+    })?;
+    tokens.finish()?;
+    Ok(divisor)
+}
+
+fn parse_throw(line: &str, condition: &str) -> Result<i32, ParseError> {
+    let mut tokens = Tokens::new(line);
+    tokens.literal("If")?;
+    tokens.literal(condition)?;
+    tokens.literal("throw")?;
+    tokens.literal("to")?;
+    tokens.literal("monkey")?;
+    let monkey = tokens.number()?;
+    tokens.finish()?;
+    Ok(monkey)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(content)
+    }
+
+    fn part1(monkeys: &Self::Input) -> impl Display {
+        task_1(monkeys)
+    }
+
+    fn part2(monkeys: &Self::Input) -> impl Display {
+        task_2(monkeys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input).to_string(), "10605");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input).to_string(), "2713310158");
+    }
+
+    #[test]
+    fn rejects_malformed_notes() {
+        let parse_err = |notes: &str| Day11::parse(notes).err().unwrap();
+        let err = parse_err(&EXAMPLE.replace("Monkey 2:", "Monkey 3:"));
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (15, 8, "'2:'")
+        );
+        let err = parse_err(&EXAMPLE.replace("items: 79, 60", "items: 79, x60"));
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (16, 23, "number")
+        );
+        let err = parse_err(&EXAMPLE.replace("old + 6", "old % 6"));
+        assert_eq!((err.line, err.column), (10, 24));
+        let err = parse_err(&EXAMPLE.replace("by 19", "by 0"));
+        assert_eq!((err.line, err.expected.as_str()), (11, "positive number"));
+        let err = parse_err(&EXAMPLE.replace("If false: throw to monkey 3\n\n", ""));
+        assert_eq!((err.line, err.column), (6, 5));
+        assert_eq!(err.expected, "'If'");
+        // Monkeys throw to other monkeys that exist
+        let err = parse_err(
+            &EXAMPLE.replace("If false: throw to monkey 0", "If false: throw to monkey 4"),
+        );
+        assert_eq!((err.line, err.column), (13, 31));
+        assert_eq!(err.expected, "another monkey from 0 to 3");
+        let err =
+            parse_err(&EXAMPLE.replace("If true: throw to monkey 1", "If true: throw to monkey 2"));
+        assert_eq!((err.line, err.column), (19, 30));
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day11>(EXAMPLE);
+    }
+}
@@ -0,0 +1,230 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use grid::{Grid, Pos};
+use std::fmt::Display;
+use tracing::{enabled, trace, Level};
+
+fn task_1(spf: &ShortPathFinder) -> u32 {
+    let start = spf.heightmap.start();
+    spf.len_to(start).unwrap()
+}
+
+fn task_2(spf: &ShortPathFinder) -> u32 {
+    let start = spf.heightmap.start();
+    let mut p = spf.len_to(start).unwrap();
+
+    for (pos, ch) in spf.heightmap.field.iter() {
+        if *ch == 'a' {
+            if let Some(np) = spf.len_to(pos) {
+                if np < p {
+                    p = np;
+                }
+            }
+        }
+    }
+
+    p
+}
+
+struct Heightmap {
+    field: Grid<char>,
+}
+
+impl Heightmap {
+    /// Rows of equal length, with a single start `S` and a single end `E`.
+    fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let rows = parse_lines(content, |line| {
+            let row = line.trim();
+            let mut unknown = row.matches(|ch| !matches!(ch, 'a'..='z' | 'S' | 'E'));
+            if let Some(ch) = unknown.next() {
+                return Err(ParseError::at_token(
+                    line,
+                    ch,
+                    "height from 'a' to 'z', 'S' or 'E'",
+                ));
+            }
+            let n = row.chars().count();
+            match width.get_or_insert(n) {
+                w if *w == n => Ok(row.to_string()),
+                w => Err(ParseError::at_token(
+                    line,
+                    row,
+                    format!("row of {} squares", w),
+                )),
+            }
+        })?;
+        let Some(last) = rows.last() else {
+            return Err(ParseError::at_end("", "heightmap").at_line(1, ""));
+        };
+
+        for sym in ['S', 'E'] {
+            let mut found = rows.iter().enumerate().flat_map(|(idx, row)| {
+                row.match_indices(sym)
+                    .map(move |(col, _)| (idx, &row[col..col + 1]))
+            });
+            match (found.next(), found.next()) {
+                (Some(_), None) => {}
+                (None, _) => {
+                    let err = ParseError::at_end(last, format!("'{}'", sym));
+                    return Err(err.at_line(rows.len(), last));
+                }
+                (Some(_), Some((idx, token))) => {
+                    let err =
+                        ParseError::at_token(&rows[idx], token, format!("a single '{}'", sym));
+                    return Err(err.at_line(idx + 1, &rows[idx]));
+                }
+            }
+        }
+
+        Ok(Self {
+            field: Grid::parse(&rows.join("\n"), |ch| ch),
+        })
+    }
+
+    fn start(&self) -> Pos {
+        self._find_sym('S')
+    }
+
+    fn end(&self) -> Pos {
+        self._find_sym('E')
+    }
+
+    fn _find_sym(&self, sym: char) -> Pos {
+        self.field.find(&sym).unwrap()
+    }
+}
+
+pub struct ShortPathFinder {
+    heightmap: Heightmap,
+    meta: Grid<Option<u32>>,
+}
+
+impl ShortPathFinder {
+    fn new(heightmap: Heightmap, endpoint: Pos) -> Self {
+        let meta = Grid::new(heightmap.field.width(), heightmap.field.height(), None);
+        let mut ret = Self { heightmap, meta };
+
+        ret.meta[endpoint] = Some(0);
+        ret._precalc_path(endpoint);
+
+        ret
+    }
+
+    fn _height(&self, pos: Pos) -> u32 {
+        match self.heightmap.field[pos] {
+            'E' => ('z' as u32) + 1,
+            'S' => ('a' as u32) - 1,
+            ch => ch as u32,
+        }
+    }
+
+    fn _precalc_path(&mut self, endpoint: Pos) {
+        let mut wave = vec![endpoint];
+        let mut nxt_len: u32 = 1;
+
+        while !wave.is_empty() {
+            let mut nxt_wave = Vec::new();
+
+            for p in wave {
+                let p_h = self._height(p);
+                let neighs: Vec<Pos> = self.heightmap.field.neighbors4(p).collect();
+                for n in neighs {
+                    if self.meta[n].is_some() {
+                        continue;
+                    }
+                    let n_h = self._height(n);
+                    if p_h == n_h || p_h == n_h + 1 || n_h > p_h {
+                        self.meta[n] = Some(nxt_len);
+                        nxt_wave.push(n);
+                    }
+                }
+            }
+
+            nxt_len += 1;
+            wave = nxt_wave;
+
+            if enabled!(Level::TRACE) {
+                let mut reached = String::new();
+                for (p, ch) in self.heightmap.field.iter() {
+                    reached.push(if self.meta[p].is_some() { *ch } else { '.' });
+                    if p.x + 1 == self.heightmap.field.width() as i32 {
+                        reached.push('\n');
+                    }
+                }
+                trace!("wave {}\n{}", nxt_len - 1, reached);
+            }
+        }
+    }
+
+    fn len_to(&self, p: Pos) -> Option<u32> {
+        self.meta[p]
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = ShortPathFinder;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        let hm = Heightmap::parse(content)?;
+        let end = hm.end();
+        Ok(ShortPathFinder::new(hm, end))
+    }
+
+    fn part1(spf: &Self::Input) -> impl Display {
+        task_1(spf)
+    }
+
+    fn part2(spf: &Self::Input) -> impl Display {
+        task_2(spf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input).to_string(), "31");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input).to_string(), "29");
+    }
+
+    fn parse_err(content: &str) -> ParseError {
+        Day12::parse(content).err().unwrap()
+    }
+
+    #[test]
+    fn rejects_malformed_heightmaps() {
+        let err = parse_err("Sab\nc1E");
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = parse_err("Sab\ncE");
+        assert_eq!((err.line, err.expected.as_str()), (2, "row of 3 squares"));
+        let err = parse_err("Sab\ncdz");
+        assert_eq!((err.line, err.expected.as_str()), (2, "'E'"));
+        let err = parse_err("SaE\nSdz");
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 1, "a single 'S'")
+        );
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day12>(EXAMPLE);
+    }
+}
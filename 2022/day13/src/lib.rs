@@ -0,0 +1,270 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use std::{cmp::Ordering, fmt::Display};
+use tracing::trace;
+
+// Packets go in pairs separated by blank lines
+fn parse_packets(content: &str) -> Result<Vec<Value>, ParseError> {
+    let lines: Vec<&str> = content.lines().collect();
+    let packets: Vec<Value> = parse_lines(content, |line| match line.trim() {
+        "" => Ok(None),
+        _ => Value::parse(line).map(Some),
+    })?
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if packets.len() % 2 == 1 {
+        let (idx, last) = lines
+            .iter()
+            .enumerate()
+            .rfind(|(_, line)| !line.trim().is_empty())
+            .unwrap();
+        let err = ParseError::at_end(last, "second packet of the pair");
+        return Err(err.at_line(idx + 1, last));
+    }
+    Ok(packets)
+}
+
+fn task_1(packets: &[Value]) -> usize {
+    let mut answer = 0;
+    for (idx, pair) in packets.chunks(2).enumerate() {
+        if pair[0] < pair[1] {
+            answer += idx + 1;
+        }
+    }
+
+    answer
+}
+
+fn task_2(packets: &[Value]) -> usize {
+    let mut values = packets.to_vec();
+
+    values.push(Value::divider(2));
+    values.push(Value::divider(6));
+
+    values.sort();
+
+    let mut answer = 1;
+    for (idx, val) in values.iter().enumerate() {
+        if *val == Value::divider(2) {
+            answer *= idx + 1;
+        }
+        if *val == Value::divider(6) {
+            answer *= idx + 1;
+        }
+    }
+
+    answer
+}
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Int(i32),
+    Lst(Vec<Value>),
+}
+
+impl Value {
+    /// The divider packet `[[n]]`.
+    fn divider(n: i32) -> Self {
+        Value::Lst(vec![Value::Lst(vec![Value::Int(n)])])
+    }
+
+    /// Parses a packet, a list taking up the whole line.
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let packet = line.trim();
+        if !packet.starts_with('[') {
+            return Err(error_at_start(line, packet, "'['"));
+        }
+        let (value, rest) = Value::parse_value(line, packet)?;
+        match rest.trim_start() {
+            "" => Ok(value),
+            rest => Err(error_at_start(line, rest, "end of line")),
+        }
+    }
+
+    /// The value at the start of `text`, a slice of `line`, and the text after it.
+    fn parse_value<'a>(line: &str, text: &'a str) -> Result<(Value, &'a str), ParseError> {
+        trace!("Try parsing: {text}");
+        let text = text.trim_start();
+        if let Some(rest) = text.strip_prefix('[') {
+            let mut items = Vec::new();
+            if let Some(rest) = rest.trim_start().strip_prefix(']') {
+                return Ok((Value::Lst(items), rest));
+            }
+            let mut rest = rest;
+            loop {
+                let (item, tail) = Value::parse_value(line, rest)?;
+                items.push(item);
+                let tail = tail.trim_start();
+                if let Some(tail) = tail.strip_prefix(',') {
+                    rest = tail;
+                } else if let Some(tail) = tail.strip_prefix(']') {
+                    return Ok((Value::Lst(items), tail));
+                } else {
+                    return Err(error_at_start(line, tail, "',' or ']'"));
+                }
+            }
+        }
+
+        let n_digits = text
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len());
+        let (number, rest) = text.split_at(n_digits);
+        match number.parse() {
+            Ok(n) => Ok((Value::Int(n), rest)),
+            Err(_) if n_digits > 0 => Err(ParseError::at_token(line, number, "number")),
+            Err(_) => Err(error_at_start(line, text, "number or '['")),
+        }
+    }
+}
+
+/// Error about the first char of `text`, a slice of `line`, or about the end of the line.
+fn error_at_start(line: &str, text: &str, expected: &str) -> ParseError {
+    match text.chars().next() {
+        Some(ch) => ParseError::at_token(line, &text[..ch.len_utf8()], expected),
+        None => ParseError::at_end(line, expected),
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self {
+            Value::Int(x) => match other {
+                Value::Int(y) => x.cmp(y),
+                _ => Value::Lst(vec![Value::Int(*x)]).cmp(other),
+            },
+            Value::Lst(lv) => match other {
+                Value::Int(x) => self.cmp(&Value::Lst(vec![Value::Int(*x)])),
+                Value::Lst(rv) => {
+                    for (l, r) in lv.iter().zip(rv.iter()) {
+                        let cmp = l.cmp(r);
+                        if cmp != Ordering::Equal {
+                            return cmp;
+                        }
+                    }
+                    lv.len().cmp(&rv.len())
+                }
+            },
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Value>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_packets(content)
+    }
+
+    fn part1(packets: &Self::Input) -> impl Display {
+        task_1(packets)
+    }
+
+    fn part2(packets: &Self::Input) -> impl Display {
+        task_2(packets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input).to_string(), "13");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part2(&input).to_string(), "140");
+    }
+
+    fn packet(line: &str) -> Value {
+        Value::parse(line).unwrap()
+    }
+
+    #[test]
+    fn value_ordering() {
+        assert!(packet("[1,1,3,1,1]") < packet("[1,1,5,1,1]"));
+        assert!(packet("[[1],[2,3,4]]") < packet("[[1],4]"));
+        assert!(packet("[9]") > packet("[[8,7,6]]"));
+        assert!(packet("[[4,4],4,4]") < packet("[[4,4],4,4,4]"));
+        assert!(packet("[7,7,7,7]") > packet("[7,7,7]"));
+        assert!(packet("[]") < packet("[3]"));
+        assert!(packet("[[[]]]") > packet("[[]]"));
+        assert!(packet("[1,[2,[3,[4,[5,6,7]]]],8,9]") > packet("[1,[2,[3,[4,[5,6,0]]]],8,9]"));
+    }
+
+    #[test]
+    fn value_mixed_equality() {
+        assert_eq!(packet("[[1]]"), packet("[1]"));
+        assert_eq!(packet("[[2],3]"), packet("[2,[3]]"));
+        assert_ne!(packet("[10]"), packet("[1,0]"));
+    }
+
+    #[test]
+    fn rejects_malformed_packets() {
+        let err = Day13::parse("[1,[2]\n[3]").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (1, 7, "',' or ']'")
+        );
+        let err = Day13::parse("[1]\n[1,x]").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = Day13::parse("[1] 2\n[3]").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        let err = Day13::parse("[1]\n[2]\n\n[3]\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (4, "second packet of the pair")
+        );
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day13>(EXAMPLE);
+    }
+}
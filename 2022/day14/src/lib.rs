@@ -0,0 +1,285 @@
+use aoc_core::{parse_lines, ParseError, Simulation, Solution, Tokens, Visualize};
+use geom::Vec2;
+use grid::Grid;
+use std::fmt::Display;
+use tracing::debug;
+
+fn task_1(paths: &[Vec<Point>]) -> u32 {
+    let mut cave = Cave::new(paths, false);
+    while cave.drop_sand() {}
+    cave.units_of_sand
+}
+
+fn task_2(paths: &[Vec<Point>]) -> u32 {
+    let mut cave = Cave::new(paths, true);
+    while cave.drop_sand() {}
+    cave.units_of_sand
+}
+
+/// The cave scan with sand poured into it from the source, one unit at a time.
+struct Cave {
+    canvas: Canvas,
+    source: Point,
+    max_y: i32,
+    floor: bool, // whether there is a floor two rows below the lowest rock
+    min_x: i32,
+    max_x: i32,
+    units_of_sand: u32,
+}
+
+impl Cave {
+    fn new(paths: &[Vec<Point>], floor: bool) -> Self {
+        let w = WIDTH;
+        let mut canvas = Canvas::new(HEIGHT, w);
+        let mut max_y = 0;
+        let mut min_x = i32::MAX;
+        let mut max_x = 0;
+        for path in paths {
+            for p in path {
+                max_y = max_y.max(p.y);
+                min_x = min_x.min(p.x);
+                max_x = max_x.max(p.x);
+            }
+            for i in 1..path.len() {
+                canvas.paint_line(path[i - 1], path[i]);
+            }
+        }
+        let source = Point { x: 500, y: 0 };
+        canvas.paint_char_at('+', source);
+        if floor {
+            canvas.paint_line(
+                Point::new(0, max_y + 2),
+                Point::new((w as i32) - 1, max_y + 2),
+            );
+        }
+
+        debug!("Max y: {}", max_y);
+
+        Self {
+            canvas,
+            source,
+            max_y,
+            floor,
+            min_x,
+            max_x,
+            units_of_sand: 0,
+        }
+    }
+
+    // Lets a unit of sand fall from the source until it comes to rest. Returns `false` once
+    // sand stops piling up: the unit falls into the abyss, or it rests on the source
+    fn drop_sand(&mut self) -> bool {
+        let mut p = self.source;
+
+        loop {
+            let p1 = Point::new(p.x, p.y + 1);
+            let p2 = Point::new(p.x - 1, p.y + 1);
+            let p3 = Point::new(p.x + 1, p.y + 1);
+            match [p1, p2, p3]
+                .into_iter()
+                .find(|&pn| self.canvas.char(pn) == '.')
+            {
+                Some(pn) => p = pn,
+                None => break,
+            }
+
+            if !self.floor && p.y == self.max_y {
+                return false;
+            }
+        }
+
+        let old_ch = self.canvas.char(p);
+        self.canvas.paint_char_at('o', p);
+        self.units_of_sand += 1;
+
+        old_ch != '+'
+    }
+}
+
+impl Simulation for Cave {
+    fn step(&mut self) -> bool {
+        self.drop_sand()
+    }
+
+    fn render(&self) -> String {
+        // With a floor the sand piles up into a triangle below the source
+        let (left, right) = match self.floor {
+            true => (
+                self.source.x - self.max_y - 3,
+                self.source.x + self.max_y + 3,
+            ),
+            false => (self.min_x - 1, self.max_x + 1),
+        };
+        format!(
+            "{} units of sand at rest\n{}",
+            self.units_of_sand,
+            self.canvas
+                .window(Point::new(left, 0), self.max_y + 3, right - left + 1)
+        )
+    }
+}
+
+type Point = Vec2<i32>;
+
+/// Size of the canvas; the floor is two rows below the lowest rock, so rocks must be higher.
+const WIDTH: usize = 1000;
+const HEIGHT: usize = 200;
+
+fn parse_path(line: &str) -> Result<Vec<Point>, ParseError> {
+    const POINT: &str = "'<x>,<y>' inside the cave";
+    let mut tokens = Tokens::new(line);
+    let mut path = vec![tokens.parse_with(POINT, parse_point)?];
+    loop {
+        let Some(arrow) = tokens.rest().next() else {
+            return Ok(path);
+        };
+        if arrow != "->" {
+            return Err(tokens.error(arrow, "'->'"));
+        }
+        let word = tokens.word(POINT)?;
+        let p = parse_point(word).ok_or_else(|| tokens.error(word, POINT))?;
+        let last = path[path.len() - 1];
+        if p.x != last.x && p.y != last.y {
+            return Err(tokens.error(word, "point on a vertical or horizontal line"));
+        }
+        path.push(p);
+    }
+}
+
+fn parse_point(word: &str) -> Option<Point> {
+    let (x, y) = word.split_once(',')?;
+    let p = Point::new(x.parse().ok()?, y.parse().ok()?);
+    let inside = (0..WIDTH as i32).contains(&p.x) && (0..HEIGHT as i32 - 2).contains(&p.y);
+    inside.then_some(p)
+}
+
+struct Canvas {
+    buf: Grid<char>,
+}
+
+impl Canvas {
+    fn new(h: usize, w: usize) -> Self {
+        Self {
+            buf: Grid::new(w, h, '.'),
+        }
+    }
+
+    fn paint_char_at(&mut self, ch: char, p: Point) {
+        self.buf[p] = ch;
+    }
+
+    fn paint_line(&mut self, a: Point, b: Point) {
+        let step = (b - a).signum();
+
+        if step.x * step.y != 0 {
+            panic!("Only vertical and horizontal lines supported")
+        }
+
+        let mut r = a;
+        while r != b {
+            self.paint_char_at('#', r);
+            r += step;
+        }
+        self.paint_char_at('#', b);
+    }
+
+    fn char(&self, p: Point) -> char {
+        self.buf[p]
+    }
+
+    fn window(&self, topleft: Point, h: i32, w: i32) -> String {
+        let mut window = String::new();
+        for i in 0..h {
+            window.extend((0..w).map(|j| self.buf[topleft + Point::new(j, i)]));
+            window.push('\n');
+        }
+        window
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<Point>>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(content, parse_path)
+    }
+
+    fn part1(paths: &Self::Input) -> impl Display {
+        task_1(paths)
+    }
+
+    fn part2(paths: &Self::Input) -> impl Display {
+        task_2(paths)
+    }
+}
+
+impl Visualize for Day14 {
+    fn simulation(paths: &Self::Input, part: u32) -> Box<dyn Simulation + '_> {
+        Box::new(Cave::new(paths, part == 2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn part1_example() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part1(&input).to_string(), "24");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part2(&input).to_string(), "93");
+    }
+
+    #[test]
+    fn cave_renders_sand() {
+        let paths = Day14::parse(EXAMPLE).unwrap();
+        let mut cave = Cave::new(&paths, false);
+        for _ in 0..5 {
+            assert!(cave.step());
+        }
+        assert_eq!(
+            cave.render(),
+            "\
+5 units of sand at rest
+.......+....
+............
+............
+............
+.....#...##.
+.....#...#..
+...###...#..
+.......o.#..
+.....oooo#..
+.#########..
+............
+............
+"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        let err = Day14::parse("498,4 -> 498,6\n503,4 -> 502,5").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 10));
+        let err = Day14::parse("498,4 => 498,6").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        let err = Day14::parse("498,4 -> 498,x").unwrap_err();
+        assert_eq!(err.expected, "'<x>,<y>' inside the cave");
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day14>(EXAMPLE);
+    }
+}
@@ -0,0 +1,180 @@
+use aoc_core::{parse_lines, ParseError, Solution, Tokens};
+use geom::{Interval, IntervalSet, Vec2};
+use std::{collections::HashSet, fmt::Display};
+use tracing::debug;
+
+fn task_1(pairs: &[Pair], y: i32) -> u32 {
+    let covered: IntervalSet<i32> = pairs.iter().filter_map(|pair| pair.covered(y)).collect();
+    // Every beacon is in the range of its sensor, several sensors may share one
+    let beacons: HashSet<i32> = pairs
+        .iter()
+        .filter(|pair| pair.beacon.coord.y == y)
+        .map(|pair| pair.beacon.coord.x)
+        .collect();
+
+    let count = covered.len().expect("covered part of the row is too wide");
+    count as u32 - beacons.len() as u32
+}
+
+fn task_2(pairs: &[Pair], max_y: i32) -> i64 {
+    let row = Interval::new(0, max_y).unwrap();
+    // Reused for every row, so that the scan does not allocate
+    let mut covered = IntervalSet::new();
+    for y in 0..max_y + 1 {
+        if y % 100000 == 0 {
+            debug!("Scan y: {}", y);
+        }
+        covered.clear();
+        covered.extend(pairs.iter().filter_map(|pair| pair.covered(y)));
+        if let Some(x) = covered.first_missing(row) {
+            return (x as i64) * 4000000 + (y as i64);
+        }
+    }
+
+    unreachable!()
+}
+
+type Point = Vec2<i32>;
+
+struct Sensor {
+    coord: Point,
+}
+
+struct Beacon {
+    coord: Point,
+}
+
+pub struct Pair {
+    sensor: Sensor,
+    beacon: Beacon,
+}
+
+impl Pair {
+    fn new(sensor: Sensor, beacon: Beacon) -> Self {
+        Self { sensor, beacon }
+    }
+
+    fn manh(&self) -> i32 {
+        self.sensor.coord.manhattan(self.beacon.coord)
+    }
+
+    /// Columns of row `y` within the range of the sensor.
+    fn covered(&self, y: i32) -> Option<Interval<i32>> {
+        let rest = self.manh() - (self.sensor.coord.y - y).abs();
+        let x = self.sensor.coord.x;
+        Interval::new(x - rest, x + rest)
+    }
+}
+
+fn parse_pair(line: &str) -> Result<Pair, ParseError> {
+    let mut tokens = Tokens::new(line);
+
+    tokens.literal("Sensor")?;
+    tokens.literal("at")?;
+    let sx = parse_coord(&mut tokens, "x=", ",")?;
+    let sy = parse_coord(&mut tokens, "y=", ":")?;
+
+    tokens.literal("closest")?;
+    tokens.literal("beacon")?;
+    tokens.literal("is")?;
+    tokens.literal("at")?;
+    let bx = parse_coord(&mut tokens, "x=", ",")?;
+    let by = parse_coord(&mut tokens, "y=", "")?;
+    tokens.finish()?;
+
+    Ok(Pair::new(
+        Sensor {
+            coord: Point::new(sx, sy),
+        },
+        Beacon {
+            coord: Point::new(bx, by),
+        },
+    ))
+}
+
+fn parse_coord(tokens: &mut Tokens, prefix: &str, suffix: &str) -> Result<i32, ParseError> {
+    let expected = format!("'{}<number>{}'", prefix, suffix);
+    tokens.parse_with(&expected, |word| {
+        word.strip_prefix(prefix)?
+            .strip_suffix(suffix)?
+            .parse()
+            .ok()
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Pair>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(content, parse_pair)
+    }
+
+    fn part1(pairs: &Self::Input) -> impl Display {
+        task_1(pairs, 2000000)
+    }
+
+    fn part2(pairs: &Self::Input) -> impl Display {
+        task_2(pairs, 4000000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn part1_example() {
+        let pairs = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(task_1(&pairs, 10), 26);
+    }
+
+    #[test]
+    fn part2_example() {
+        let pairs = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(task_2(&pairs, 20), 56000011);
+    }
+
+    #[test]
+    fn rejects_malformed_reports() {
+        let err = Day15::parse("Sensor at x=2, y=18 closest beacon is at x=-2, y=15")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (1, 16));
+        assert_eq!(err.expected, "'y=<number>:'");
+        let err = Day15::parse(&EXAMPLE.replace("x=20, y=1:", "x=20, y=one:"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (14, 17));
+        let err = Day15::parse("Sensor at x=2, y=18: closest beacon is at x=-2, y=15.")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.expected.as_str()), (1, "'y=<number>'"));
+        let err = Day15::parse("Sensor at x=2, y=18: nearest beacon is at x=-2, y=15")
+            .err()
+            .unwrap();
+        assert_eq!((err.column, err.expected.as_str()), (22, "'closest'"));
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day15>(EXAMPLE);
+    }
+}
@@ -0,0 +1,554 @@
+use aoc_core::{parse_lines, ParseError, Solution, Tokens};
+#[cfg(any(test, feature = "gen"))]
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+use tracing::{debug, enabled, trace, Level};
+
+pub struct Network {
+    valves: Vec<Valve>,
+    ws: Vec<Vec<u32>>, // distances between valves, zeroed for valves with no flow
+    start: usize,      // index of 'AA'
+    valve_assignee: Vec<ValveAssignee>,
+    significant_valve_counter: usize,
+    rate_sum: u32,
+}
+
+impl Network {
+    fn i2v(&self) -> HashMap<usize, &Valve> {
+        self.valves.iter().enumerate().collect()
+    }
+}
+
+fn build_network(valves: Vec<Valve>) -> Network {
+    let mut v2i: HashMap<&str, usize> = HashMap::new(); // valve2index;
+    let mut i2v: HashMap<usize, &Valve> = HashMap::new();
+    for (idx, valve) in valves.iter().enumerate() {
+        v2i.insert(&valve.name, idx);
+        i2v.insert(idx, valve);
+    }
+
+    let sz = valves.len();
+    let mut ws: Vec<Vec<u32>> = vec![vec![u32::MAX; sz]; sz]; // edge weights matrix
+    for valve in &valves {
+        let i = v2i.get(&valve.name[..]).unwrap();
+        for friend in &valve.friends {
+            let j = v2i.get(&friend[..]).unwrap();
+            ws[*i][*j] = 1;
+            ws[*j][*i] = 1;
+        }
+    }
+
+    for k in 0..sz {
+        for i in 0..sz {
+            for j in 0..sz {
+                let v = ws[i][k].saturating_add(ws[k][j]);
+                ws[i][j] = ws[i][j].min(v);
+            }
+        }
+    }
+
+    let mut valve_assignee = vec![ValveAssignee::Man; sz];
+    let mut no_assignee_counter = 0;
+    let mut rate_sum = 0;
+    for i in 0..sz {
+        let valve = *i2v.get(&i).unwrap();
+        if valve.rate == 0 && valve.name != "AA" {
+            ws[i].fill(0);
+            for row in ws.iter_mut() {
+                row[i] = 0;
+            }
+            valve_assignee[i] = ValveAssignee::No;
+            no_assignee_counter += 1;
+        }
+        rate_sum += valve.rate;
+    }
+
+    let significant_valve_counter = sz - no_assignee_counter - 1; // -1 stands for 'AA'
+    debug!("Valves to consider count: {}", significant_valve_counter);
+
+    if enabled!(Level::TRACE) {
+        for i in 0..sz {
+            trace!("{} - {}", i, i2v.get(&i).unwrap().name);
+        }
+
+        for row in &ws {
+            let row: Vec<String> = row
+                .iter()
+                .map(|item| match *item {
+                    u32::MAX => "x".to_string(),
+                    item => item.to_string(),
+                })
+                .collect();
+            trace!("{}", row.join(" "));
+        }
+    }
+
+    let start = *v2i.get("AA").unwrap();
+    Network {
+        valves,
+        ws,
+        start,
+        valve_assignee,
+        significant_valve_counter,
+        rate_sum,
+    }
+}
+
+fn task_1(net: &Network) -> u32 {
+    let i2v = net.i2v();
+    let mut walker = Walker1::new(&i2v, &net.ws);
+    walker = walker.bfs(net.start, 0, net.rate_sum);
+    walker.best_solution
+}
+
+fn task_2(net: &Network) -> u32 {
+    let i2v = net.i2v();
+    let mut ans = 0;
+
+    let n = 1 << net.significant_valve_counter;
+    for i in 1..n {
+        if i % 100 == 0 {
+            debug!("{}/{}", i, n);
+        }
+
+        let mut idx: usize = 0;
+
+        let value_assignee: Vec<ValveAssignee> = net
+            .valve_assignee
+            .iter()
+            .map(|it| match it {
+                ValveAssignee::No => *it,
+                _ => {
+                    if (i & (1 << idx)) > 0 {
+                        idx += 1;
+                        ValveAssignee::Man
+                    } else {
+                        idx += 1;
+                        ValveAssignee::Elephant
+                    }
+                }
+            })
+            .collect();
+
+        let mut man = Walker1::new2(
+            &i2v,
+            &net.ws,
+            value_assignee.clone(),
+            ValveAssignee::Man,
+            26,
+        );
+        man = man.bfs(net.start, 0, net.rate_sum);
+
+        let mut elephant =
+            Walker1::new2(&i2v, &net.ws, value_assignee, ValveAssignee::Elephant, 26);
+        elephant = elephant.bfs(net.start, 0, net.rate_sum);
+
+        let sm = man.best_solution + elephant.best_solution;
+        ans = ans.max(sm);
+    }
+
+    ans
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValveAssignee {
+    No,
+    Man,
+    Elephant,
+}
+
+struct Walker1<'a> {
+    i2v: &'a HashMap<usize, &'a Valve>,
+    ws: &'a Vec<Vec<u32>>,
+
+    visited: Vec<bool>,
+    time: u32,
+    best_solution: u32,
+
+    whose: ValveAssignee,
+    valve_assignee: Vec<ValveAssignee>,
+}
+
+impl<'a> Walker1<'a> {
+    fn new(i2v: &'a HashMap<usize, &'a Valve>, ws: &'a Vec<Vec<u32>>) -> Self {
+        Self {
+            i2v,
+            ws,
+            visited: vec![false; ws.len()],
+            time: 30,
+            best_solution: 0,
+            whose: ValveAssignee::No,
+            valve_assignee: Vec::new(),
+        }
+    }
+
+    fn new2(
+        i2v: &'a HashMap<usize, &'a Valve>,
+        ws: &'a Vec<Vec<u32>>,
+        valve_assignee: Vec<ValveAssignee>,
+        whose: ValveAssignee,
+        time: u32,
+    ) -> Self {
+        Self {
+            i2v,
+            ws,
+            visited: vec![false; ws.len()],
+            time,
+            best_solution: 0,
+            whose,
+            valve_assignee,
+        }
+    }
+
+    fn bfs(mut self, node: usize, released: u32, rest_rate: u32) -> Self {
+        self.visited[node] = true;
+
+        if released + rest_rate * self.time < self.best_solution {
+            self.visited[node] = false;
+            return self;
+        }
+
+        if self.time == 0 {
+            self.visited[node] = false;
+            return self;
+        }
+
+        let rate = self.i2v.get(&node).unwrap().rate;
+        if rate > 0 {
+            self.time -= 1;
+        }
+
+        let released_here = rate * self.time;
+        self.best_solution = self.best_solution.max(released + released_here);
+
+        trace!(
+            "{} Visiting {} with rate {} and released here {}. rem.time {}",
+            str::repeat(" ", self.time as usize),
+            self.i2v.get(&node).unwrap().name,
+            rate,
+            released_here,
+            self.time
+        );
+
+        for nxt in 0..self.ws.len() {
+            trace!(
+                "{} Try to visit {}, host: {}",
+                str::repeat(" ", self.time as usize),
+                self.i2v.get(&nxt).unwrap().name,
+                self.i2v.get(&node).unwrap().name
+            );
+            if self.whose != ValveAssignee::No && self.valve_assignee[nxt] != self.whose {
+                continue;
+            }
+
+            if self.visited[nxt] {
+                continue;
+            }
+            let w = self.ws[node][nxt];
+            if w == 0 {
+                continue;
+            }
+            if w > self.time {
+                continue;
+            }
+
+            self.time -= w;
+            self = self.bfs(nxt, released + released_here, rest_rate - rate);
+            self.time += w;
+        }
+
+        if rate > 0 {
+            self.time += 1;
+        }
+
+        self.visited[node] = false;
+        self
+    }
+}
+
+struct Valve {
+    name: String,
+    rate: u32,
+    friends: Vec<String>,
+}
+
+impl Valve {
+    fn new(name: String, rate: u32, friends: Vec<String>) -> Self {
+        Self {
+            name,
+            rate,
+            friends,
+        }
+    }
+}
+
+fn parse_valve(line: &str) -> Result<Valve, ParseError> {
+    let mut tokens = Tokens::new(line);
+    tokens.literal("Valve")?;
+    let name = tokens.word("valve name")?.to_string();
+    tokens.literal("has")?;
+    tokens.literal("flow")?;
+    let rate = tokens.parse_with("'rate=<number>;'", parse_rate)?;
+    tokens.parse_with("'tunnel' or 'tunnels'", |w| {
+        matches!(w, "tunnel" | "tunnels").then_some(())
+    })?;
+    tokens.parse_with("'lead' or 'leads'", |w| {
+        matches!(w, "lead" | "leads").then_some(())
+    })?;
+    tokens.literal("to")?;
+    tokens.parse_with("'valve' or 'valves'", |w| {
+        matches!(w, "valve" | "valves").then_some(())
+    })?;
+
+    let friends: Vec<String> = tokens
+        .rest()
+        .map(|friend| friend.strip_suffix(',').unwrap_or(friend).to_string())
+        .collect();
+    if friends.is_empty() {
+        return Err(ParseError::at_end(line, "valve name"));
+    }
+
+    Ok(Valve::new(name, rate, friends))
+}
+
+// Every valve is named once, tunnels lead to valves of the scan and the walk starts at 'AA'
+fn parse_network(content: &str) -> Result<Network, ParseError> {
+    let valves = parse_lines(content, parse_valve)?;
+    let lines: Vec<&str> = content.lines().collect();
+
+    let mut names = HashSet::new();
+    for (idx, valve) in valves.iter().enumerate() {
+        if !names.insert(valve.name.as_str()) {
+            let line = lines[idx];
+            let name = line.split_whitespace().nth(1).unwrap();
+            return Err(
+                ParseError::at_token(line, name, "name of another valve").at_line(idx + 1, line)
+            );
+        }
+    }
+    for (idx, &line) in lines.iter().enumerate().take(valves.len()) {
+        // The valves the tunnels lead to follow the 9 words of `Valve .. to valves`
+        for word in line.split_whitespace().skip(9) {
+            if !names.contains(word.strip_suffix(',').unwrap_or(word)) {
+                let err = ParseError::at_token(line, word, "name of a valve of the scan");
+                return Err(err.at_line(idx + 1, line));
+            }
+        }
+    }
+    if !names.contains("AA") {
+        let last = lines.get(valves.len().max(1) - 1).copied().unwrap_or("");
+        let err = ParseError::at_end(last, "valve 'AA'");
+        return Err(err.at_line(valves.len().max(1), last));
+    }
+
+    Ok(build_network(valves))
+}
+
+fn parse_rate(word: &str) -> Option<u32> {
+    word.strip_prefix("rate=")?.strip_suffix(';')?.parse().ok()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Network;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_network(content)
+    }
+
+    fn part1(net: &Self::Input) -> impl Display {
+        task_1(net)
+    }
+
+    fn part2(net: &Self::Input) -> impl Display {
+        task_2(net)
+    }
+}
+
+/// Generates a random connected network of valves with `AA` among them.
+///
+/// At most seven valves have a non-zero flow rate, so the answers stay cheap to brute-force.
+#[cfg(any(test, feature = "gen"))]
+pub fn generate(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let n_valves = rng.random_range(2..=10);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < n_valves {
+        let name: String = (0..2).map(|_| rng.random_range('A'..='Z')).collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    // A random spanning tree keeps every valve reachable, extra tunnels add cycles
+    let mut tunnels = vec![Vec::new(); n_valves];
+    for i in 1..n_valves {
+        let j = rng.random_range(0..i);
+        tunnels[i].push(j);
+        tunnels[j].push(i);
+    }
+    for _ in 0..rng.random_range(0..n_valves) {
+        let i = rng.random_range(0..n_valves);
+        let j = rng.random_range(0..n_valves);
+        if i != j && !tunnels[i].contains(&j) {
+            tunnels[i].push(j);
+            tunnels[j].push(i);
+        }
+    }
+
+    let mut rates = vec![0; n_valves];
+    let mut flowing: Vec<usize> = (1..n_valves).collect();
+    flowing.shuffle(&mut rng);
+    for &i in flowing.iter().take(rng.random_range(0..=7)) {
+        rates[i] = rng.random_range(1..=25);
+    }
+
+    let mut lines: Vec<String> = (0..n_valves)
+        .map(|i| {
+            let friends: Vec<&str> = tunnels[i].iter().map(|&j| names[j].as_str()).collect();
+            let lead = match friends.len() {
+                1 => "tunnel leads to valve",
+                _ => "tunnels lead to valves",
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}",
+                names[i],
+                rates[i],
+                lead,
+                friends.join(", ")
+            )
+        })
+        .collect();
+    lines.shuffle(&mut rng);
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // The most pressure released for every set of opened valves, found by walking the
+    // tunnels minute by minute
+    fn naive(net: &Network, minutes: u32) -> HashMap<u32, u32> {
+        let index: HashMap<&str, usize> = net
+            .valves
+            .iter()
+            .enumerate()
+            .map(|(i, valve)| (valve.name.as_str(), i))
+            .collect();
+
+        let mut states = HashMap::from([((net.start, 0), 0)]);
+        let mut best = HashMap::from([(0, 0)]);
+        for minute in 1..=minutes {
+            let mut next: HashMap<(usize, u32), u32> = HashMap::new();
+            let mut push = |state, released| {
+                let entry = next.entry(state).or_insert(0);
+                *entry = (*entry).max(released);
+            };
+            for (&(at, opened), &released) in &states {
+                let valve = &net.valves[at];
+                if valve.rate > 0 && opened & (1 << at) == 0 {
+                    push(
+                        (at, opened | 1 << at),
+                        released + valve.rate * (minutes - minute),
+                    );
+                }
+                for friend in &valve.friends {
+                    push((index[friend.as_str()], opened), released);
+                }
+            }
+            states = next;
+
+            for (&(_, opened), &released) in &states {
+                let entry = best.entry(opened).or_insert(0);
+                *entry = (*entry).max(released);
+            }
+        }
+        best
+    }
+
+    const EXAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn part1_example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part1(&input).to_string(), "1651");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part2(&input).to_string(), "1707");
+    }
+
+    #[test]
+    fn rejects_malformed_scans() {
+        let parse_err = |scan: &str| Day16::parse(scan).err().unwrap();
+        let err = parse_err(&EXAMPLE.replace("rate=13;", "rate=13"));
+        assert_eq!((err.line, err.column), (2, 19));
+        assert_eq!(err.expected, "'rate=<number>;'");
+        let err = parse_err(&EXAMPLE.replace("leads to valve GG", "goes to valve GG"));
+        assert_eq!((err.line, err.column), (8, 35));
+        assert_eq!(err.expected, "'lead' or 'leads'");
+        let err = parse_err(&EXAMPLE.replace("leads to valve GG", "leads to valve"));
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (8, 49, "valve name")
+        );
+        let err = parse_err(&EXAMPLE.replace("Valve FF", "Valve EE"));
+        assert_eq!((err.line, err.column), (6, 7));
+        assert_eq!(err.expected, "name of another valve");
+        let err = parse_err(&EXAMPLE.replace("valves CC, AA, EE", "valves CC, ZZ, EE"));
+        assert_eq!((err.line, err.column), (4, 55));
+        assert_eq!(err.expected, "name of a valve of the scan");
+        let err = parse_err(&EXAMPLE.replace("AA", "KK"));
+        assert_eq!((err.line, err.expected.as_str()), (10, "valve 'AA'"));
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day16>(EXAMPLE);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn part1_matches_naive(seed in any::<u64>()) {
+            let net = Day16::parse(&generate(seed)).unwrap();
+            let best = naive(&net, 30);
+            prop_assert_eq!(task_1(&net), best.into_values().max().unwrap());
+        }
+
+        #[test]
+        fn part2_matches_naive(seed in any::<u64>()) {
+            let net = Day16::parse(&generate(seed)).unwrap();
+            let best = naive(&net, 26);
+            let mut expected = 0;
+            for (&man, &a) in &best {
+                for (&elephant, &b) in &best {
+                    if man & elephant == 0 {
+                        expected = expected.max(a + b);
+                    }
+                }
+            }
+            prop_assert_eq!(task_2(&net), expected);
+        }
+    }
+}
@@ -0,0 +1,536 @@
+use aoc_core::{ParseError, Simulation, Solution, Visualize};
+use geom::Vec2;
+use grid::Grid;
+use std::{collections::HashMap, fmt::Display};
+use tracing::{enabled, trace, Level};
+
+fn task_1(jets: &[Action]) -> usize {
+    let controller = Controller::new(jets);
+    let rock_producer = CirclularRockProducer::new(configure_rocks());
+
+    let mut game = Game::new(7, 3, 2, controller, rock_producer);
+
+    game.play(2022);
+    game.print();
+
+    game.tower_height
+}
+
+fn task_2(jets: &[Action]) -> u64 {
+    let controller = Controller::new(jets);
+
+    let rocks = configure_rocks();
+    let rock_count = rocks.len();
+    let rock_producer = CirclularRockProducer::new(rocks);
+
+    let mut game = Game::new(7, 3, 2, controller, rock_producer);
+
+    // maket the game stable
+    let mut rock_fallen = rock_count * 1000;
+    game.play(rock_fallen);
+
+    let mut fingerprint2fallenrock: HashMap<i64, Vec<(usize, usize)>> = HashMap::new();
+
+    'main: loop {
+        game.play(rock_count);
+        rock_fallen += rock_count;
+        let fingerprint = game.make_fingerprint();
+
+        let v = fingerprint2fallenrock.entry(fingerprint).or_default();
+
+        v.push((rock_fallen, game.tower_height));
+
+        if v.len() > 9 {
+            let step_diff = v[1].0 - v[0].0;
+            let height_diff = v[1].1 - v[0].1;
+
+            for i in 2..v.len() {
+                if v[i].0 - v[i - 1].0 != step_diff {
+                    continue 'main;
+                }
+                if v[i].1 - v[i - 1].1 != height_diff {
+                    continue 'main;
+                }
+            }
+            // test on cycle passed
+
+            let mut tower_height = game.tower_height as u64;
+            let mut rock_to_fall = 1_000_000_000_000 - (rock_fallen as u64);
+
+            tower_height += (rock_to_fall / (step_diff as u64)) * (height_diff as u64);
+            rock_to_fall %= step_diff as u64;
+
+            let old_height = game.tower_height;
+            game.play(rock_to_fall as usize);
+            tower_height += (game.tower_height - old_height) as u64;
+            return tower_height;
+        }
+    }
+}
+
+fn configure_rocks() -> Vec<Rock> {
+    vec![
+        Rock::new("####".to_string(), 4, 1),
+        Rock::new(".#.###.#.".to_string(), 3, 3),
+        Rock::new("..#..####".to_string(), 3, 3),
+        Rock::new("####".to_string(), 1, 4),
+        Rock::new("####".to_string(), 2, 2),
+    ]
+}
+
+#[derive(Clone, Copy)]
+pub enum Action {
+    Right,
+    Left,
+}
+
+fn parse_jets(content: &str) -> Result<Vec<Action>, ParseError> {
+    let line = content.trim();
+    line.char_indices()
+        .map(|(idx, raw_action)| match raw_action {
+            '<' => Ok(Action::Left),
+            '>' => Ok(Action::Right),
+            _ => Err(ParseError::at_token(
+                line,
+                &line[idx..idx + raw_action.len_utf8()],
+                "'<' or '>'",
+            )
+            .at_line(1, line)),
+        })
+        .collect()
+}
+
+struct Controller<'a> {
+    pattern: &'a [Action],
+    nxt_idx: usize,
+}
+
+impl<'a> Controller<'a> {
+    fn new(pattern: &'a [Action]) -> Self {
+        Self {
+            pattern,
+            nxt_idx: 0,
+        }
+    }
+
+    fn next_action(&mut self) -> Action {
+        let action = self.pattern[self.nxt_idx];
+        self.nxt_idx = (self.nxt_idx + 1) % self.pattern.len();
+        action
+    }
+}
+
+struct CirclularRockProducer {
+    rocks: Vec<Rock>,
+    nxt_idx: usize,
+}
+
+#[derive(Clone)]
+struct Rock {
+    content: String,
+    width: usize,
+    height: usize,
+}
+
+impl Rock {
+    fn new(content: String, width: usize, height: usize) -> Self {
+        assert!(width * height == content.len());
+        Self {
+            content,
+            width,
+            height,
+        }
+    }
+
+    fn cell_type(&self, rel_p: Point) -> CellType {
+        let idx = (rel_p.y as usize) * self.width + (rel_p.x as usize);
+        match self.content.chars().nth(idx).unwrap() {
+            '.' => CellType::Air,
+            '#' => CellType::Rock,
+            c => panic!("Unexpected cell '{}' in rock", c),
+        }
+    }
+}
+
+impl CirclularRockProducer {
+    fn new(rocks: Vec<Rock>) -> Self {
+        Self { rocks, nxt_idx: 0 }
+    }
+
+    fn next_rock(&mut self) -> Rock {
+        let nxt_rock = self.rocks[self.nxt_idx].clone();
+        self.nxt_idx = (self.nxt_idx + 1) % self.rocks.len();
+        nxt_rock
+    }
+}
+
+struct Game<'a> {
+    field: Grid<bool>,
+    field_width: usize,
+    tower_height: usize,
+
+    spawn_height: usize,
+    spawn_x_offset: usize,
+
+    controller: Controller<'a>,
+    rock_producer: CirclularRockProducer,
+}
+
+impl<'a> Game<'a> {
+    fn new(
+        field_width: usize,
+        spawn_height: usize,
+        spawn_x_offset: usize,
+        controller: Controller<'a>,
+        rock_producer: CirclularRockProducer,
+    ) -> Self {
+        Self {
+            field: Grid::new(field_width, 0, false),
+            tower_height: 0,
+            field_width,
+            spawn_height,
+            spawn_x_offset,
+            controller,
+            rock_producer,
+        }
+    }
+
+    fn _in_bounds(&self, p: Point) -> bool {
+        p.y >= 0 && p.x >= 0 && p.x < (self.field_width as i32)
+    }
+
+    fn _cell_type(&self, p: Point) -> CellType {
+        if !self._in_bounds(p) {
+            panic!("Point {:?} is out of bound", p);
+        }
+        if self.field.get(p).copied().unwrap_or(false) {
+            CellType::Rock
+        } else {
+            CellType::Air
+        }
+    }
+
+    fn _put_rock_at(&mut self, p: Point) {
+        if !self._in_bounds(p) {
+            panic!("Point {:?} is out of bound", p);
+        }
+        let height = self.field.height();
+        if p.y as usize >= height {
+            self.field.extend_rows(p.y as usize - height + 10, false);
+        }
+
+        assert!(!self.field[p]);
+        self.field[p] = true;
+
+        let h = (p.y + 1) as usize;
+        if h > self.tower_height {
+            self.tower_height = h;
+        }
+    }
+
+    fn _spawn_point(&self) -> Point {
+        Point {
+            x: self.spawn_x_offset as i32,
+            y: (self.tower_height + self.spawn_height) as i32,
+        }
+    }
+
+    fn play(&mut self, n_rocks: usize) {
+        for _ in 0..n_rocks {
+            let rock = FallingRock::new(self.rock_producer.next_rock(), self._spawn_point());
+            self._play_rock(rock);
+        }
+    }
+
+    fn make_fingerprint(&self) -> i64 {
+        let mut fingerprint: i64 = 0;
+
+        let end_idx = self.tower_height * self.field_width;
+        let start_idx = end_idx - 60;
+
+        for i in start_idx..end_idx {
+            let p = Point::new((i % self.field_width) as i32, (i / self.field_width) as i32);
+            if self.field.get(p).copied().unwrap_or(false) {
+                fingerprint += 1 << (i - start_idx);
+            }
+        }
+        fingerprint
+    }
+
+    fn print(&self) {
+        if !enabled!(Level::TRACE) {
+            return;
+        }
+        let from_y = self.tower_height + 3;
+        trace!("\n{}", self.draw(from_y, from_y, None, true));
+    }
+
+    // Draws `rows` rows of the chamber downwards from the one below `from_y`, with the
+    // falling rock if there is one and the row numbers if `labeled`
+    fn draw(
+        &self,
+        from_y: usize,
+        rows: usize,
+        falling: Option<&FallingRock>,
+        labeled: bool,
+    ) -> String {
+        let label = |y: &dyn Display| match labeled {
+            true => format!("[{:>3}] ", y),
+            false => String::new(),
+        };
+        let mut field = String::new();
+        for i in 0..rows.min(from_y) {
+            let y = (from_y - 1 - i) as i32;
+            field += &label(&y);
+            field.push('|');
+            for x in 0..self.field_width as i32 {
+                let p = Point { x, y };
+                field.push(match self._cell_type(p) {
+                    _ if falling.is_some_and(|rock| rock.covers(p)) => '@',
+                    CellType::Air => '.',
+                    CellType::Rock => '#',
+                });
+            }
+            field += "|\n";
+        }
+        if rows >= from_y {
+            field += &label(&"");
+            field += &format!("+{}+", str::repeat("-", self.field_width));
+        }
+        field
+    }
+
+    fn _play_rock(&mut self, rock: FallingRock) {
+        let mut rock = rock;
+        while self._move_rock(&mut rock) {}
+    }
+
+    // Pushes the rock by the next jet and lets it fall by one unit, returns `false` once it
+    // comes to rest
+    fn _move_rock(&mut self, rock: &mut FallingRock) -> bool {
+        // LR-action
+        let action = self.controller.next_action();
+        let dx = match action {
+            Action::Right => 1,
+            Action::Left => -1,
+        };
+
+        rock.anchor.x += dx;
+        if !self._is_valid_rock(rock) {
+            rock.anchor.x -= dx;
+        }
+
+        // move-down
+        rock.anchor.y -= 1;
+        if !self._is_valid_rock(rock) {
+            rock.anchor.y += 1;
+            self._freeze_rock(rock);
+            return false;
+        }
+        true
+    }
+
+    fn _is_valid_rock(&self, rock: &FallingRock) -> bool {
+        let anchor = rock.anchor;
+        let rock = &rock.rock;
+        for r in 0..rock.height {
+            let r = r as i32;
+            for c in 0..rock.width {
+                let c = c as i32;
+
+                if rock.cell_type(Point {
+                    x: c,
+                    y: (rock.height as i32) - r - 1,
+                }) == CellType::Rock
+                {
+                    let field_point = anchor + Point::new(c, r);
+
+                    if !self._in_bounds(field_point) {
+                        return false;
+                    }
+
+                    if self._cell_type(field_point) == CellType::Rock {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    fn _freeze_rock(&mut self, rock: &FallingRock) {
+        // todo: get rid of duplicate
+        let anchor = rock.anchor;
+        let rock = &rock.rock;
+        for r in 0..rock.height {
+            let r = r as i32;
+            for c in 0..rock.width {
+                let c = c as i32;
+                if rock.cell_type(Point {
+                    x: c,
+                    y: (rock.height as i32) - r - 1,
+                }) == CellType::Rock
+                {
+                    let field_point = anchor + Point::new(c, r);
+                    self._put_rock_at(field_point);
+                }
+            }
+        }
+    }
+}
+
+type Point = Vec2<i32>;
+
+#[derive(PartialEq, Eq)]
+enum CellType {
+    Air,
+    Rock,
+}
+
+struct FallingRock {
+    rock: Rock,
+    anchor: Point,
+}
+
+impl FallingRock {
+    fn new(rock: Rock, anchor: Point) -> Self {
+        Self { rock, anchor }
+    }
+
+    fn covers(&self, p: Point) -> bool {
+        let Point { x: c, y: r } = p - self.anchor;
+        let (width, height) = (self.rock.width as i32, self.rock.height as i32);
+        (0..width).contains(&c)
+            && (0..height).contains(&r)
+            && self.rock.cell_type(Point {
+                x: c,
+                y: height - r - 1,
+            }) == CellType::Rock
+    }
+}
+
+/// Rocks falling into the chamber one jet push at a time.
+struct Chamber<'a> {
+    game: Game<'a>,
+    falling: Option<FallingRock>,
+    rocks_left: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [Action], n_rocks: usize) -> Self {
+        let controller = Controller::new(jets);
+        let rock_producer = CirclularRockProducer::new(configure_rocks());
+        let mut game = Game::new(7, 3, 2, controller, rock_producer);
+        let falling = FallingRock::new(game.rock_producer.next_rock(), game._spawn_point());
+        Self {
+            game,
+            falling: Some(falling),
+            rocks_left: n_rocks,
+        }
+    }
+}
+
+impl Simulation for Chamber<'_> {
+    fn step(&mut self) -> bool {
+        let Some(rock) = &mut self.falling else {
+            return false;
+        };
+        if self.game._move_rock(rock) {
+            return true;
+        }
+
+        self.rocks_left -= 1;
+        self.falling = (self.rocks_left > 0).then(|| {
+            FallingRock::new(
+                self.game.rock_producer.next_rock(),
+                self.game._spawn_point(),
+            )
+        });
+        self.falling.is_some()
+    }
+
+    fn render(&self) -> String {
+        let top = match &self.falling {
+            Some(rock) => rock.anchor.y as usize + rock.rock.height,
+            None => self.game.tower_height,
+        };
+        format!(
+            "tower height {}\n{}",
+            self.game.tower_height,
+            self.game.draw(top, 40, self.falling.as_ref(), false)
+        )
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Action>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_jets(content)
+    }
+
+    fn part1(jets: &Self::Input) -> impl Display {
+        task_1(jets)
+    }
+
+    fn part2(jets: &Self::Input) -> impl Display {
+        task_2(jets)
+    }
+}
+
+impl Visualize for Day17 {
+    // Part 2 extrapolates the height from a cycle, its animation just keeps dropping rocks
+    fn simulation(jets: &Self::Input, part: u32) -> Box<dyn Simulation + '_> {
+        let n_rocks = if part == 1 { 2022 } else { usize::MAX };
+        Box::new(Chamber::new(jets, n_rocks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+>>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn part1_example() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part1(&input).to_string(), "3068");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part2(&input).to_string(), "1514285714288");
+    }
+
+    #[test]
+    fn chamber_shows_falling_rock() {
+        let jets = Day17::parse(EXAMPLE).unwrap();
+        let mut chamber = Chamber::new(&jets, 2);
+        // The first rock comes to rest after four pushes, the second one has been pushed once
+        for _ in 0..5 {
+            assert!(chamber.step());
+        }
+        assert_eq!(
+            chamber.render(),
+            "\
+tower height 1
+|..@....|
+|.@@@...|
+|..@....|
+|.......|
+|.......|
+|..####.|
++-------+"
+        );
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day17>(EXAMPLE);
+    }
+}
@@ -0,0 +1,146 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use geom::{BBox, Vec3};
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
+
+fn task_1(cubes: &HashSet<Point>) -> i32 {
+    let mut n_sides = 0;
+    for cube in cubes {
+        n_sides += 6;
+
+        for neighbor in cube.neighbors6() {
+            if cubes.contains(&neighbor) {
+                n_sides -= 1;
+            }
+        }
+    }
+
+    n_sides
+}
+
+fn task_2(cubes: &HashSet<Point>) -> u32 {
+    let bbox = BBox::from_points(cubes.iter().copied())
+        .unwrap()
+        .grow(Point::splat(1));
+
+    let mut outside: HashSet<Point> = HashSet::new();
+    let mut queue: VecDeque<Point> = VecDeque::new();
+
+    outside.insert(bbox.min);
+    queue.push_back(bbox.min);
+
+    while let Some(p) = queue.pop_front() {
+        for n in p.neighbors6() {
+            if !bbox.contains(n) {
+                continue;
+            }
+            if cubes.contains(&n) {
+                continue;
+            }
+            if outside.contains(&n) {
+                continue;
+            }
+            outside.insert(n);
+            queue.push_back(n);
+        }
+    }
+
+    let mut n_sides = 0;
+
+    for cube in cubes {
+        n_sides += 6;
+        for neighbor in cube.neighbors6() {
+            if cubes.contains(&neighbor) {
+                n_sides -= 1;
+                continue;
+            }
+            if !outside.contains(&neighbor) {
+                n_sides -= 1;
+            }
+        }
+    }
+
+    n_sides
+}
+
+type Point = Vec3<i32>;
+
+fn parse_point(line: &str) -> Result<Point, ParseError> {
+    let coords = line
+        .trim()
+        .split(',')
+        .map(|x| {
+            x.parse()
+                .map_err(|_| ParseError::at_token(line, x, "coordinate"))
+        })
+        .collect::<Result<Vec<i32>, _>>()?;
+    match coords[..] {
+        [x, y, z] => Ok(Point { x, y, z }),
+        _ => Err(ParseError::at_token(line, line.trim(), "'<x>,<y>,<z>'")),
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<Point>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_lines(content, parse_point)?.into_iter().collect())
+    }
+
+    fn part1(cubes: &Self::Input) -> impl Display {
+        task_1(cubes)
+    }
+
+    fn part2(cubes: &Self::Input) -> impl Display {
+        task_2(cubes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    #[test]
+    fn part1_example() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(Day18::part1(&input).to_string(), "64");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(Day18::part2(&input).to_string(), "58");
+    }
+
+    #[test]
+    fn rejects_malformed_points() {
+        let err = Day18::parse("2,2,2\n1,x,2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = Day18::parse("2,2,2\n1,2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day18>(EXAMPLE);
+    }
+}
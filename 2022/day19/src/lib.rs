@@ -1,27 +1,20 @@
+use aoc_core::{parse_lines, ParseError, Solution, Tokens};
 use std::{
     collections::HashSet,
-    env, fs,
+    fmt::Display,
     ops::{AddAssign, SubAssign},
 };
+use tracing::{debug, trace};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let content = fs::read_to_string(filename).expect("File not found");
-
-    // println!("Day19. Task1: {}", task_1(&content));
-    println!("Day19. Task2: {}", task_2(&content));
-}
-
-fn task_1(content: &str) -> i32 {
+fn task_1(blueprints: &[Blueprint]) -> i32 {
     let mut result: i32 = 0;
 
-    for blueprint in content.split("\n").map(parse_blueprint) {
+    for &blueprint in blueprints {
         let mut solver = TaskSolver1::new(blueprint);
         solver = solver.solve(24);
         result += solver.blueprint.n * solver.balance.geode;
 
-        println!("BP {} done: {}", solver.blueprint.n, solver.balance.geode);
+        debug!("BP {} done: {}", solver.blueprint.n, solver.balance.geode);
     }
 
     result
@@ -55,10 +48,10 @@ impl State {
     }
 }
 
-fn task_2(content: &str) -> i32 {
+fn task_2(blueprints: &[Blueprint]) -> i32 {
     let mut result: i32 = 1;
 
-    for (idx, blueprint) in content.split("\n").map(parse_blueprint).enumerate() {
+    for (idx, blueprint) in blueprints.iter().enumerate() {
         if idx == 3 {
             break;
         }
@@ -69,10 +62,8 @@ fn task_2(content: &str) -> i32 {
         for t in 1..=32 {
             let mut new_states: HashSet<State> = HashSet::new();
             for state in &states {
-                if t == 23 {
-                    if state.n_geode_robots < 1 {
-                        continue;
-                    }
+                if t == 23 && state.n_geode_robots < 1 {
+                    continue;
                 }
 
                 let mut st = *state;
@@ -133,13 +124,13 @@ fn task_2(content: &str) -> i32 {
                 let expected = v.geode + 5 * v.n_geode_robots;
                 new_states.retain(|s| s.geode + 5 * s.n_geode_robots + 13 >= expected);
             }
-            println!("On time {} we have {} states", t, new_states.len());
+            trace!("On time {} we have {} states", t, new_states.len());
 
             states = new_states;
         }
 
         let v = states.iter().max_by_key(|s| s.geode).unwrap();
-        println!("BP {} done: {}", blueprint.n, v.geode);
+        debug!("BP {} done: {}", blueprint.n, v.geode);
         result *= v.geode;
     }
 
@@ -195,7 +186,11 @@ impl TaskSolver1 {
         let new_res = self.robots.produce();
         let price = self._robot_price(robot_type);
 
-        // println!("{} Try robot {:?}", str::repeat(" |", minutes_left as usize), robot_type);
+        trace!(
+            "{} Try robot {:?}",
+            str::repeat(" |", minutes_left as usize),
+            robot_type
+        );
         if self._has_resources(price) {
             self.cur_balance -= price;
             self.cur_balance += new_res;
@@ -207,7 +202,11 @@ impl TaskSolver1 {
             self.cur_balance += price;
             self.cur_balance -= new_res;
         } else {
-            // println!("{} >Can't create robot with price {:?}", str::repeat(" |", minutes_left as usize), price);
+            trace!(
+                "{} >Can't create robot with price {:?}",
+                str::repeat(" |", minutes_left as usize),
+                price
+            );
         }
         self
     }
@@ -231,10 +230,7 @@ impl TaskSolver1 {
     }
 
     fn _has_resources(&self, price: ResourcePack) -> bool {
-        price.ore <= self.cur_balance.ore
-            && price.clay <= self.cur_balance.clay
-            && price.obsidian <= self.cur_balance.obsidian
-            && price.geode <= self.cur_balance.geode
+        self.cur_balance.has_resources(price)
     }
 
     fn _robot_can_be_created(&self, robot_type: ResType) -> bool {
@@ -251,8 +247,8 @@ enum ResType {
     Geode,
 }
 
-#[derive(Debug)]
-struct Blueprint {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blueprint {
     n: i32,
 
     ore_robot: ResourcePack,
@@ -332,52 +328,162 @@ impl Robots {
     }
 }
 
-fn parse_blueprint(line: &str) -> Blueprint {
-    let mut splitter = line.trim().split(": ");
-
-    let n: i32 = splitter
-        .next()
-        .unwrap()
-        .split(' ')
-        .nth(1)
-        .unwrap()
-        .parse()
-        .unwrap();
+fn parse_blueprint(line: &str) -> Result<Blueprint, ParseError> {
+    let mut tokens = Tokens::new(line);
 
-    let mut splitter = splitter.next().unwrap().split(". ");
+    tokens.literal("Blueprint")?;
+    let n = tokens.parse_with("'<number>:'", |word| word.strip_suffix(':')?.parse().ok())?;
 
-    Blueprint {
+    let blueprint = Blueprint {
         n,
-        ore_robot: parse_respack(splitter.next().unwrap()),
-        clay_robot: parse_respack(splitter.next().unwrap()),
-        obsidian_robot: parse_respack(splitter.next().unwrap()),
-        geode_robot: parse_respack(splitter.next().unwrap()),
-    }
+        ore_robot: parse_respack(&mut tokens, "ore")?,
+        clay_robot: parse_respack(&mut tokens, "clay")?,
+        obsidian_robot: parse_respack(&mut tokens, "obsidian")?,
+        geode_robot: parse_respack(&mut tokens, "geode")?,
+    };
+    tokens.finish()?;
+
+    Ok(blueprint)
 }
 
-fn parse_respack(line: &str) -> ResourcePack {
-    let mut splitter = line.trim().split(" costs ");
-    splitter.next();
+fn parse_respack(tokens: &mut Tokens, robot: &str) -> Result<ResourcePack, ParseError> {
+    tokens.literal("Each")?;
+    tokens.literal(robot)?;
+    tokens.literal("robot")?;
+    tokens.literal("costs")?;
 
     let mut respack = ResourcePack::default();
 
-    for res in splitter.next().unwrap().split(" and ") {
-        let res = if res.ends_with(".") {
-            res.get(..res.len() - 1).unwrap()
-        } else {
-            res
+    loop {
+        let count: i32 = tokens.number()?;
+        let res = tokens.word("resource")?;
+        let (name, last) = match res.strip_suffix('.') {
+            Some(name) => (name, true),
+            None => (res, false),
         };
 
-        let mut res_splitter = res.split(' ');
-        let count: i32 = res_splitter.next().unwrap().parse().unwrap();
-        match res_splitter.next().unwrap() {
+        match name {
             "ore" => respack.ore += count,
             "clay" => respack.clay += count,
             "obsidian" => respack.obsidian += count,
             "geode" => respack.geode += count,
-            other => panic!("Unexpected type of resource: '{}' in '{}'", other, res),
+            _ => return Err(tokens.error(res, "one of 'ore', 'clay', 'obsidian', 'geode'")),
         }
+
+        if last {
+            return Ok(respack);
+        }
+        tokens.literal("and")?;
     }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(content, parse_blueprint)
+    }
+
+    fn part1(blueprints: &Self::Input) -> impl Display {
+        task_1(blueprints)
+    }
+
+    fn part2(blueprints: &Self::Input) -> impl Display {
+        task_2(blueprints)
+    }
+}
 
-    respack
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    fn cost(ore: i32, clay: i32, obsidian: i32) -> ResourcePack {
+        ResourcePack {
+            ore,
+            clay,
+            obsidian,
+            geode: 0,
+        }
+    }
+
+    #[test]
+    fn parses_example_blueprints() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            input,
+            vec![
+                Blueprint {
+                    n: 1,
+                    ore_robot: cost(4, 0, 0),
+                    clay_robot: cost(2, 0, 0),
+                    obsidian_robot: cost(3, 14, 0),
+                    geode_robot: cost(2, 0, 7),
+                },
+                Blueprint {
+                    n: 2,
+                    ore_robot: cost(2, 0, 0),
+                    clay_robot: cost(3, 0, 0),
+                    obsidian_robot: cost(3, 8, 0),
+                    geode_robot: cost(3, 0, 12),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn max_geodes_of_one_blueprint() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        // The first geode robot of blueprint 1 is ready after minute 18 at the earliest.
+        assert_eq!(TaskSolver1::new(input[0]).solve(18).balance.geode, 0);
+        assert_eq!(TaskSolver1::new(input[0]).solve(19).balance.geode, 1);
+    }
+
+    #[test]
+    #[ignore = "slow: explores the whole state space of both blueprints"]
+    fn part1_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part1(&input).to_string(), "33");
+    }
+
+    #[test]
+    #[ignore = "slow: explores the whole state space of both blueprints"]
+    fn part2_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part2(&input).to_string(), "3472");
+    }
+
+    #[test]
+    fn rejects_malformed_blueprints() {
+        let err = Day19::parse(&EXAMPLE.replace("Blueprint 2:", "Blueprint 2")).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 11, "'<number>:'")
+        );
+        let err =
+            Day19::parse(&EXAMPLE.replace("3 ore and 14 clay", "3 ore and 14 sand")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 110));
+        assert_eq!(err.expected, "one of 'ore', 'clay', 'obsidian', 'geode'");
+        let err = Day19::parse(&EXAMPLE.replace(
+            "Each clay robot costs 2 ore.",
+            "Each clay robot costs 2 ore",
+        ))
+        .unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (1, "'and'"));
+        let err =
+            Day19::parse(&EXAMPLE.replace("Each geode robot costs 2 ore and 7 obsidian.", ""))
+                .unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (1, "'Each'"));
+    }
+
+    #[test]
+    fn crlf_example() {
+        let crlf = EXAMPLE.replace('\n', "\r\n") + "\r\n\r\n";
+        assert_eq!(Day19::parse(&crlf).unwrap(), Day19::parse(EXAMPLE).unwrap());
+    }
 }
@@ -0,0 +1,606 @@
+use aoc_core::{parse_lines, ParseError, Solution, Tokens};
+use serde::Deserialize;
+use std::{collections::HashMap, error::Error, fmt::Display, sync::OnceLock};
+
+/// Rules of the puzzle: rock, paper and scissors, read from the strategy guide as A/B/C
+/// against X/Y/Z.
+pub const RPS: &str = r#"
+choices = ["Rock", "Paper", "Scissors"]
+scores = [1, 2, 3]
+rival = ["A", "B", "C"]
+you = ["X", "Y", "Z"]
+outcomes = ["lost", "draw", "won"]
+
+[outcome_scores]
+won = 6
+draw = 3
+lost = 0
+"#;
+
+/// Rock-paper-scissors-lizard-Spock. In this order every choice beats the two before it
+/// in the cycle: Spock vaporizes rock, paper covers rock and disproves Spock, and so on.
+pub const RPSLS: &str = r#"
+choices = ["Rock", "Spock", "Paper", "Lizard", "Scissors"]
+scores = [1, 5, 2, 4, 3]
+rival = ["A", "B", "C", "D", "E"]
+you = ["V", "W", "X", "Y", "Z"]
+outcomes = ["lost", "lost", "draw", "won", "won"]
+
+[outcome_scores]
+won = 6
+draw = 3
+lost = 0
+"#;
+
+/// Index of a choice in [`Rules`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Choice(usize);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Won,
+    Draw,
+    Lost,
+}
+
+// The second column of the strategy guide, its meaning differs between the tasks
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Hint(usize);
+
+/// The configuration [`Rules`] are loaded from, see [`RPS`] for an example.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    choices: Vec<String>,
+    /// Score of each choice
+    scores: Vec<u32>,
+    /// Letter of each choice in the first column
+    rival: Vec<String>,
+    /// Letters of the second column: `you[i]` means choice `i` in the first task and
+    /// `outcomes[i]` in the second one
+    you: Vec<String>,
+    outcomes: Vec<Outcome>,
+    outcome_scores: OutcomeScores,
+    /// Choices each choice beats, by name. Without it every choice beats the
+    /// `(N - 1) / 2` ones before it in the cycle of `choices`, which takes an odd N.
+    #[serde(default)]
+    beats: Option<HashMap<String, Vec<String>>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OutcomeScores {
+    won: u32,
+    draw: u32,
+    lost: u32,
+}
+
+#[derive(Debug)]
+pub enum RulesError {
+    Toml(toml::de::Error),
+    /// The table is well-formed but does not describe a game.
+    Invalid(String),
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RulesError::Toml(err) => write!(f, "{}", err),
+            RulesError::Invalid(err) => write!(f, "invalid rules: {}", err),
+        }
+    }
+}
+
+impl Error for RulesError {}
+
+/// A hand game for any number of players' choices: who beats whom, how the strategy guide
+/// encodes the choices and how rounds are scored.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    choices: Vec<String>,
+    /// `beats[a][b]` if choice `a` beats choice `b`
+    beats: Vec<Vec<bool>>,
+    choice_scores: Vec<u32>,
+    rival_letters: Vec<String>,
+    hint_letters: Vec<String>,
+    hint_outcomes: Vec<Outcome>,
+    outcome_scores: OutcomeScores,
+}
+
+impl Rules {
+    pub fn from_toml(config: &str) -> Result<Self, RulesError> {
+        let config: Config = toml::from_str(config).map_err(RulesError::Toml)?;
+        let invalid = |message: String| Err(RulesError::Invalid(message));
+
+        let n = config.choices.len();
+        if n < 2 {
+            return invalid("a game takes at least two choices".to_string());
+        }
+        let lengths = [
+            ("scores", config.scores.len()),
+            ("rival", config.rival.len()),
+            ("you", config.you.len()),
+            ("outcomes", config.outcomes.len()),
+        ];
+        for (name, len) in lengths {
+            if len != n {
+                return invalid(format!("{} {} given for {} choices", len, name, n));
+            }
+        }
+        for letters in [&config.rival, &config.you] {
+            if let Some(letter) = duplicate(letters) {
+                return invalid(format!("letter '{}' is used twice", letter));
+            }
+        }
+        if let Some(choice) = duplicate(&config.choices) {
+            return invalid(format!("choice '{}' is listed twice", choice));
+        }
+
+        let beats = match &config.beats {
+            Some(table) => beats_from_table(&config.choices, table)?,
+            None if n.is_multiple_of(2) => {
+                return invalid(format!(
+                    "cyclic rules take an odd number of choices, not {}",
+                    n
+                ))
+            }
+            // Choice `a` beats `b` if `b` is at most (N - 1) / 2 steps before it
+            None => (0..n)
+                .map(|a| {
+                    (0..n)
+                        .map(|b| (1..=n / 2).contains(&((a + n - b) % n)))
+                        .collect()
+                })
+                .collect(),
+        };
+
+        Ok(Self {
+            choices: config.choices,
+            beats,
+            choice_scores: config.scores,
+            rival_letters: config.rival,
+            hint_letters: config.you,
+            hint_outcomes: config.outcomes,
+            outcome_scores: config.outcome_scores,
+        })
+    }
+
+    fn play_round(&self, rival_choice: Choice, your_choice: Choice) -> Outcome {
+        if self.beats[your_choice.0][rival_choice.0] {
+            Outcome::Won
+        } else if self.beats[rival_choice.0][your_choice.0] {
+            Outcome::Lost
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    fn decode_rival_choice(&self, choice: &str) -> Option<Choice> {
+        let idx = self.rival_letters.iter().position(|l| l == choice)?;
+        Some(Choice(idx))
+    }
+
+    fn decode_hint(&self, hint: &str) -> Option<Hint> {
+        let idx = self.hint_letters.iter().position(|l| l == hint)?;
+        Some(Hint(idx))
+    }
+
+    fn decode_your_choice(&self, hint: Hint) -> Choice {
+        Choice(hint.0)
+    }
+
+    fn decode_outcome(&self, hint: Hint) -> Outcome {
+        self.hint_outcomes[hint.0]
+    }
+
+    // The best scoring choice with the given outcome (the first one of those scoring the same)
+    fn your_choice_by_outcome(&self, rival_choice: Choice, outcome: Outcome) -> Choice {
+        (0..self.choices.len())
+            .map(Choice)
+            .filter(|&choice| self.play_round(rival_choice, choice) == outcome)
+            .max_by_key(|&choice| (self.choice_score(choice), std::cmp::Reverse(choice.0)))
+            .expect("every outcome is possible against every choice")
+    }
+
+    fn choice_score(&self, choice: Choice) -> u32 {
+        self.choice_scores[choice.0]
+    }
+
+    fn outcome_score(&self, outcome: Outcome) -> u32 {
+        let scores = &self.outcome_scores;
+        match outcome {
+            Outcome::Won => scores.won,
+            Outcome::Draw => scores.draw,
+            Outcome::Lost => scores.lost,
+        }
+    }
+
+    /// Reads a strategy guide written in the letters of these rules.
+    pub fn parse_guide(&self, content: &str) -> Result<Vec<(Choice, Hint)>, ParseError> {
+        let expected = |letters: &[String]| {
+            let quoted: Vec<String> = letters.iter().map(|l| format!("'{}'", l)).collect();
+            format!("one of {}", quoted.join(", "))
+        };
+        let rival_expected = expected(&self.rival_letters);
+        let hint_expected = expected(&self.hint_letters);
+
+        parse_lines(content, |line| {
+            let mut tokens = Tokens::new(line);
+            let rival_choice =
+                tokens.parse_with(&rival_expected, |word| self.decode_rival_choice(word))?;
+            let hint = tokens.parse_with(&hint_expected, |word| self.decode_hint(word))?;
+            tokens.finish()?;
+            Ok((rival_choice, hint))
+        })
+    }
+
+    /// Score of each round with the second column read by `decoder`.
+    pub fn round_scores<'a>(
+        &'a self,
+        rounds: &'a [(Choice, Hint)],
+        decoder: &'a impl Decoder,
+    ) -> impl Iterator<Item = u32> + 'a {
+        rounds.iter().map(|&(rival_choice, hint)| {
+            let your_choice = decoder.your_choice(self, rival_choice, hint);
+
+            let outcome = self.play_round(rival_choice, your_choice);
+            self.choice_score(your_choice) + self.outcome_score(outcome)
+        })
+    }
+
+    pub fn score(&self, rounds: &[(Choice, Hint)], decoder: &impl Decoder) -> u32 {
+        self.round_scores(rounds, decoder).sum()
+    }
+
+    /// Scores of the guide for every way of reading the second column as your choice.
+    pub fn what_if(&self, rounds: &[(Choice, Hint)]) -> WhatIf<'_> {
+        let mut permutation: Vec<usize> = (0..self.choices.len()).collect();
+        let mut mappings = Vec::new();
+        loop {
+            let mapping = Mapping(permutation.iter().copied().map(Choice).collect());
+            let rounds: Vec<u32> = self.round_scores(rounds, &mapping).collect();
+            mappings.push(MappingScore {
+                total: rounds.iter().sum(),
+                rounds,
+                mapping,
+            });
+            if !next_permutation(&mut permutation) {
+                break;
+            }
+        }
+        WhatIf {
+            rules: self,
+            mappings,
+        }
+    }
+}
+
+/// How the second column of a strategy guide is read.
+pub trait Decoder {
+    /// Your choice against `rival_choice` in a round with the given hint.
+    fn your_choice(&self, rules: &Rules, rival_choice: Choice, hint: Hint) -> Choice;
+}
+
+/// The second column is your choice, as encoded by the rules (the first task).
+pub struct ByChoice;
+
+impl Decoder for ByChoice {
+    fn your_choice(&self, rules: &Rules, _rival_choice: Choice, hint: Hint) -> Choice {
+        rules.decode_your_choice(hint)
+    }
+}
+
+/// The second column is the outcome of the round (the second task).
+pub struct ByOutcome;
+
+impl Decoder for ByOutcome {
+    fn your_choice(&self, rules: &Rules, rival_choice: Choice, hint: Hint) -> Choice {
+        rules.your_choice_by_outcome(rival_choice, rules.decode_outcome(hint))
+    }
+}
+
+/// The second column is your choice, the `i`-th letter meaning the `i`-th choice given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mapping(pub Vec<Choice>);
+
+impl Decoder for Mapping {
+    fn your_choice(&self, _rules: &Rules, _rival_choice: Choice, hint: Hint) -> Choice {
+        self.0[hint.0]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MappingScore {
+    pub mapping: Mapping,
+    pub total: u32,
+    /// Score of each round
+    pub rounds: Vec<u32>,
+}
+
+/// The scores of a guide under every mapping of its second column to choices, the
+/// identity first.
+pub struct WhatIf<'a> {
+    rules: &'a Rules,
+    pub mappings: Vec<MappingScore>,
+}
+
+impl WhatIf<'_> {
+    /// The mapping scoring the most (the first one of those scoring the same).
+    pub fn best(&self) -> &MappingScore {
+        self.mappings.iter().rev().max_by_key(|m| m.total).unwrap()
+    }
+
+    /// The mapping scoring the least (the first one of those scoring the same).
+    pub fn worst(&self) -> &MappingScore {
+        self.mappings.iter().min_by_key(|m| m.total).unwrap()
+    }
+
+    fn describe(&self, mapping: &Mapping) -> String {
+        let rules = self.rules;
+        let pairs: Vec<String> = rules
+            .hint_letters
+            .iter()
+            .zip(&mapping.0)
+            .map(|(letter, choice)| format!("{}={}", letter, rules.choices[choice.0]))
+            .collect();
+        pairs.join(" ")
+    }
+}
+
+impl Display for WhatIf<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for m in &self.mappings {
+            let rounds: Vec<String> = m.rounds.iter().map(|s| s.to_string()).collect();
+            writeln!(
+                f,
+                "{}: {} ({})",
+                self.describe(&m.mapping),
+                m.total,
+                rounds.join(" ")
+            )?;
+        }
+        let (best, worst) = (self.best(), self.worst());
+        writeln!(f, "best: {}: {}", self.describe(&best.mapping), best.total)?;
+        write!(
+            f,
+            "worst: {}: {}",
+            self.describe(&worst.mapping),
+            worst.total
+        )
+    }
+}
+
+// Rearranges `items` into the next permutation in lexicographic order, returns `false`
+// (leaving them as they are) if they already are in the last one
+fn next_permutation(items: &mut [usize]) -> bool {
+    let Some(i) = (1..items.len()).rev().find(|&i| items[i - 1] < items[i]) else {
+        return false;
+    };
+    let j = (i..items.len())
+        .rev()
+        .find(|&j| items[j] > items[i - 1])
+        .unwrap();
+    items.swap(i - 1, j);
+    items[i..].reverse();
+    true
+}
+
+impl Rules {
+    /// The rules of the puzzle, read from [`RPS`] once.
+    pub fn rps() -> &'static Rules {
+        static RULES: OnceLock<Rules> = OnceLock::new();
+        RULES.get_or_init(|| Rules::from_toml(RPS).unwrap())
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::rps().clone()
+    }
+}
+
+fn duplicate(items: &[String]) -> Option<&str> {
+    let mut seen = std::collections::HashSet::new();
+    items
+        .iter()
+        .find(|item| !seen.insert(item.as_str()))
+        .map(String::as_str)
+}
+
+// Exactly one choice of every pair has to beat the other one, and every choice has to
+// beat some choice and lose to another so that every outcome can be played for
+fn beats_from_table(
+    choices: &[String],
+    table: &HashMap<String, Vec<String>>,
+) -> Result<Vec<Vec<bool>>, RulesError> {
+    let index = |name: &String| {
+        choices
+            .iter()
+            .position(|c| c == name)
+            .ok_or_else(|| RulesError::Invalid(format!("unknown choice '{}'", name)))
+    };
+
+    let n = choices.len();
+    let mut beats = vec![vec![false; n]; n];
+    for (winner, losers) in table {
+        let a = index(winner)?;
+        for loser in losers {
+            beats[a][index(loser)?] = true;
+        }
+    }
+    for a in 0..n {
+        for b in a..n {
+            if (a == b && beats[a][b]) || (a != b && beats[a][b] == beats[b][a]) {
+                return Err(RulesError::Invalid(format!(
+                    "exactly one of '{}' and '{}' has to beat the other",
+                    choices[a], choices[b]
+                )));
+            }
+        }
+    }
+    for a in 0..n {
+        if !beats[a].contains(&true) || !(0..n).any(|b| beats[b][a]) {
+            return Err(RulesError::Invalid(format!(
+                "'{}' has to beat a choice and lose to another",
+                choices[a]
+            )));
+        }
+    }
+    Ok(beats)
+}
+
+fn task_1(rounds: &[(Choice, Hint)]) -> u32 {
+    Rules::rps().score(rounds, &ByChoice)
+}
+
+fn task_2(rounds: &[(Choice, Hint)]) -> u32 {
+    Rules::rps().score(rounds, &ByOutcome)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Choice, Hint)>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        Rules::rps().parse_guide(content)
+    }
+
+    fn part1(rounds: &Self::Input) -> impl Display {
+        task_1(rounds)
+    }
+
+    fn part2(rounds: &Self::Input) -> impl Display {
+        task_2(rounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+A Y
+B X
+C Z";
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input).to_string(), "15");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input).to_string(), "12");
+    }
+
+    #[test]
+    fn what_if_example() {
+        let rules = Rules::default();
+        let guide = rules.parse_guide(EXAMPLE).unwrap();
+        let what_if = rules.what_if(&guide);
+
+        let totals: Vec<u32> = what_if.mappings.iter().map(|m| m.total).collect();
+        assert_eq!(totals, [15, 6, 15, 15, 15, 24]);
+        assert_eq!(what_if.best().rounds, [8, 9, 7]);
+        assert_eq!(
+            what_if.to_string().lines().collect::<Vec<_>>(),
+            [
+                "X=Rock Y=Paper Z=Scissors: 15 (8 1 6)",
+                "X=Rock Y=Scissors Z=Paper: 6 (3 1 2)",
+                "X=Paper Y=Rock Z=Scissors: 15 (4 5 6)",
+                "X=Paper Y=Scissors Z=Rock: 15 (3 5 7)",
+                "X=Scissors Y=Rock Z=Paper: 15 (4 9 2)",
+                "X=Scissors Y=Paper Z=Rock: 24 (8 9 7)",
+                "best: X=Scissors Y=Paper Z=Rock: 24",
+                "worst: X=Rock Y=Scissors Z=Paper: 6",
+            ]
+        );
+
+        let rpsls = Rules::from_toml(RPSLS).unwrap();
+        assert_eq!(rpsls.what_if(&[]).mappings.len(), 120);
+    }
+
+    #[test]
+    fn rpsls_rules() {
+        let rules = Rules::from_toml(RPSLS).unwrap();
+        let choice = |name| Choice(rules.choices.iter().position(|c| c == name).unwrap());
+        let wins = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+        for (winner, loser) in wins {
+            assert_eq!(
+                rules.play_round(choice(loser), choice(winner)),
+                Outcome::Won
+            );
+            assert_eq!(
+                rules.play_round(choice(winner), choice(loser)),
+                Outcome::Lost
+            );
+        }
+
+        // Against rock: rock draws (1 + 3), Spock wins (5 + 6) and scissors lose (3)
+        let guide = rules.parse_guide("A V\nA W\nA Z").unwrap();
+        assert_eq!(rules.score(&guide, &ByChoice), 18);
+        // Losing twice with lizard rather than scissors (4), winning with Spock (5 + 6)
+        assert_eq!(rules.score(&guide, &ByOutcome), 19);
+    }
+
+    #[test]
+    fn explicit_table_matches_cycle() {
+        let table = format!(
+            "{}\n[beats]\nRock = [\"Scissors\"]\nPaper = [\"Rock\"]\nScissors = [\"Paper\"]\n",
+            RPS
+        );
+        let rules = Rules::from_toml(&table).unwrap();
+        assert_eq!(rules.beats, Rules::default().beats);
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        let invalid =
+            |config: &str| matches!(Rules::from_toml(config), Err(RulesError::Invalid(_)));
+        assert!(invalid(&RPS.replace("[1, 2, 3]", "[1, 2]")));
+        assert!(invalid(&RPS.replace("\"Z\"]", "\"X\"]")));
+        assert!(invalid(
+            &RPS.replace("\"Scissors\"]", "\"Scissors\", \"Well\"]")
+                .replace("[1, 2, 3]", "[1, 2, 3, 4]")
+                .replace("\"C\"]", "\"C\", \"D\"]")
+                .replace("\"Z\"]", "\"Z\", \"W\"]")
+                .replace("\"won\"]", "\"won\", \"won\"]")
+        ));
+        // Rock beating both others leaves no way to lose against it
+        assert!(invalid(&format!(
+            "{}\n[beats]\nRock = [\"Paper\", \"Scissors\"]\nScissors = [\"Paper\"]\n",
+            RPS
+        )));
+        assert!(matches!(
+            Rules::from_toml("choices = 3"),
+            Err(RulesError::Toml(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_letters() {
+        let err = Day2::parse("A Y\nD X").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "one of 'A', 'B', 'C'");
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day2>(EXAMPLE);
+    }
+}
@@ -1,18 +1,18 @@
-use std::{collections::HashMap, env, fs, num};
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let content = fs::read_to_string(filename).expect("File not found");
-
-    // println!("Day15. Task1: {}", task_1(&content));
-    println!("Day15. Task2: {}", task_2(&content));
+use aoc_core::{parse_lines, ParseError, Solution, Tokens};
+use std::{collections::HashMap, fmt::Display};
+use tracing::{debug, enabled, trace, Level};
+
+fn parse_number(line: &str) -> Result<i64, ParseError> {
+    let mut tokens = Tokens::new(line);
+    let n = tokens.number()?;
+    tokens.finish()?;
+    Ok(n)
 }
 
-fn task_1(content: &str) -> i64 {
-    let mut numbers: Vec<_> = content
-        .split("\n")
-        .map(|x| x.parse().unwrap())
+fn task_1(input: &[i64]) -> i64 {
+    let mut numbers: Vec<_> = input
+        .iter()
+        .copied()
         .enumerate()
         .map(|(id, n)| Item { id, n })
         .collect();
@@ -39,7 +39,7 @@ fn task_1(content: &str) -> i64 {
         shift *= dir;
 
         let mut cur_idx = idx;
-        // println!("{} moves with shift {}", item.n, shift);
+        trace!("{} moves with shift {}", item.n, shift);
 
         while shift > 0 {
             let nxt_idx = if cur_idx == 0 && dir < 0 {
@@ -65,22 +65,22 @@ fn task_1(content: &str) -> i64 {
     let n1 = numbers[(zero_idx + 1000) % numbers.len()];
     let n2 = numbers[(zero_idx + 2000) % numbers.len()];
     let n3 = numbers[(zero_idx + 3000) % numbers.len()];
-    println!("{} + {} + {}", n1.n, n2.n, n3.n);
+    debug!("{} + {} + {}", n1.n, n2.n, n3.n);
 
     n1.n + n2.n + n3.n
 }
 
-fn task_2(content: &str) -> i64 {
-    const decription_key: i64 = 811589153;
-    const n_pass: usize = 10;
+fn task_2(input: &[i64]) -> i64 {
+    const DECRIPTION_KEY: i64 = 811589153;
+    const N_PASS: usize = 10;
 
-    let mut numbers: Vec<_> = content
-        .split("\n")
-        .map(|x| x.parse::<i64>().unwrap())
+    let mut numbers: Vec<_> = input
+        .iter()
+        .copied()
         .enumerate()
         .map(|(id, n)| Item {
             id,
-            n: n * decription_key,
+            n: n * DECRIPTION_KEY,
         })
         .collect();
 
@@ -89,7 +89,7 @@ fn task_2(content: &str) -> i64 {
         id2idx.insert(n.id, idx);
     }
 
-    for round in 0..n_pass {
+    for round in 0..N_PASS {
         let mut round_id2idx = id2idx.clone();
         let mut next_id = 0;
         while !round_id2idx.is_empty() {
@@ -108,7 +108,7 @@ fn task_2(content: &str) -> i64 {
             shift *= dir;
 
             let mut cur_idx = idx;
-            // println!("{} moves with shift {}", item.n, shift);
+            trace!("{} moves with shift {}", item.n, shift);
 
             while shift > 0 {
                 let nxt_idx = if cur_idx == 0 && dir < 0 {
@@ -131,11 +131,10 @@ fn task_2(content: &str) -> i64 {
             }
         }
 
-        // print!("After round {}: ", round + 1);
-        // for item in &numbers {
-        //     print!("{} ", item.n);
-        // }
-        // println!("\n--");
+        if enabled!(Level::TRACE) {
+            let items: Vec<String> = numbers.iter().map(|item| item.n.to_string()).collect();
+            trace!("After round {}: {}", round + 1, items.join(" "));
+        }
     }
 
     let zero_idx = numbers.iter().position(|item| item.n == 0).unwrap();
@@ -143,7 +142,7 @@ fn task_2(content: &str) -> i64 {
     let n1 = numbers[(zero_idx + 1000) % numbers.len()];
     let n2 = numbers[(zero_idx + 2000) % numbers.len()];
     let n3 = numbers[(zero_idx + 3000) % numbers.len()];
-    println!("{} + {} + {}", n1.n, n2.n, n3.n);
+    debug!("{} + {} + {}", n1.n, n2.n, n3.n);
 
     n1.n + n2.n + n3.n
 }
@@ -153,3 +152,52 @@ struct Item {
     id: usize,
     n: i64,
 }
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(content, parse_number)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        task_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        task_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1
+2
+-3
+3
+-2
+0
+4";
+
+    #[test]
+    fn part1_example() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(Day20::part1(&input).to_string(), "3");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(Day20::part2(&input).to_string(), "1623178306");
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day20>(EXAMPLE);
+    }
+}
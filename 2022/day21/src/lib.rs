@@ -1,21 +1,9 @@
-use std::{collections::HashMap, env, fs, ops::Add};
+use aoc_core::{parse_lines, ParseError, Solution, Tokens};
+use std::{collections::HashMap, fmt::Display};
+use tracing::{debug, trace};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let content = fs::read_to_string(filename).expect("File not found");
-
-    // println!("Day15. Task1: {}", task_1(&content));
-    println!("Day15. Task2: {}", task_2(&content));
-}
-
-fn task_1(content: &str) -> i64 {
-    let mut name2expr: HashMap<String, Expr> = HashMap::from_iter(
-        content
-            .split("\n")
-            .map(|line| line.split_once(": ").unwrap())
-            .map(|(name, raw_expr)| (name.to_string(), Expr::parse(raw_expr))),
-    );
+fn task_1(name2expr: &HashMap<String, Expr>) -> i64 {
+    let mut name2expr = name2expr.clone();
 
     let mut stack = Vec::new();
     stack.push("root".to_owned());
@@ -24,7 +12,7 @@ fn task_1(content: &str) -> i64 {
         let last = stack.last().unwrap();
 
         let relax = match name2expr.get(last).unwrap() {
-            Expr::Number(x) => {
+            Expr::Number(_x) => {
                 stack.pop();
                 continue;
             }
@@ -49,7 +37,7 @@ fn task_1(content: &str) -> i64 {
         *name2expr.get_mut(last).unwrap() = Expr::Number(relax);
     }
 
-    match name2expr.get(&"root".to_owned()).unwrap() {
+    match name2expr.get("root").unwrap() {
         Expr::Number(x) => *x,
         Expr::Math(_, _, _) => panic!("Root is not evaluated"),
     }
@@ -66,7 +54,7 @@ fn check_guess(name2expr: &HashMap<String, Expr>, my_guess: i64) -> (i64, i64) {
         let last = stack.last().unwrap();
 
         let relax = match name2expr.get(last).unwrap() {
-            Expr::Number(x) => {
+            Expr::Number(_x) => {
                 stack.pop();
                 continue;
             }
@@ -98,25 +86,15 @@ fn check_guess(name2expr: &HashMap<String, Expr>, my_guess: i64) -> (i64, i64) {
     unreachable!();
 }
 
-fn task_2(content: &str) -> i64 {
-    let mut name2expr: HashMap<String, Expr> = HashMap::from_iter(
-        content
-            .split("\n")
-            .map(|line| line.split_once(": ").unwrap())
-            .map(|(name, raw_expr)| (name.to_string(), Expr::parse(raw_expr))),
-    );
-
-    println!("MY ANSWER: {:?}", check_guess(&name2expr, 3617613952379));
-    println!("MY ANSWER: {:?}", check_guess(&name2expr, 3617613952378)); // both answers, but only this acceptable
-
+fn task_2(name2expr: &HashMap<String, Expr>) -> i64 {
     let mut min_guess = -100000000;
-    let (lc1, rc1) = check_guess(&name2expr, min_guess);
+    let (lc1, rc1) = check_guess(name2expr, min_guess);
     if lc1 == rc1 {
         return min_guess;
     }
 
     let mut max_guess = 100000000;
-    let (lc2, rc2) = check_guess(&name2expr, max_guess);
+    let (lc2, rc2) = check_guess(name2expr, max_guess);
     if lc2 == rc2 {
         return max_guess;
     }
@@ -127,7 +105,7 @@ fn task_2(content: &str) -> i64 {
     if guess_affects_root_lhs && guess_affects_root_rhs {
         panic!("not supported");
     }
-    if !guess_affects_root_lhs && !guess_affects_root_lhs {
+    if !guess_affects_root_lhs && !guess_affects_root_rhs {
         panic!("guesses don't affect subtrees (or we are just unlucky with the guesses)");
     }
 
@@ -140,7 +118,7 @@ fn task_2(content: &str) -> i64 {
     };
 
     let affected_number = |guess: i64| {
-        let ans = check_guess(&name2expr, guess);
+        let ans = check_guess(name2expr, guess);
         if guess_affects_root_lhs {
             ans.0
         } else {
@@ -169,13 +147,13 @@ fn task_2(content: &str) -> i64 {
         }
     }
 
-    println!("target subtree {}", target);
+    debug!("target subtree {}", target);
 
     // bin search
 
     while min_guess < max_guess {
-        println!("Guess between {} and {}", min_guess, max_guess);
-        println!("a1 ~ t ~ a2: {} ~ {} ~ {}", affected_1, target, affected_2);
+        trace!("Guess between {} and {}", min_guess, max_guess);
+        trace!("a1 ~ t ~ a2: {} ~ {} ~ {}", affected_1, target, affected_2);
 
         let guess = (min_guess + max_guess) / 2;
         let affected = affected_number(guess);
@@ -219,7 +197,7 @@ fn task_2(content: &str) -> i64 {
 }
 
 #[derive(Clone)]
-enum Op {
+pub enum Op {
     Plus,
     Minus,
     Mul,
@@ -236,41 +214,152 @@ impl Op {
         }
     }
 
-    fn parse(str: &str) -> Self {
-        match str.trim() {
-            "+" => Op::Plus,
-            "-" => Op::Minus,
-            "*" => Op::Mul,
-            "/" => Op::Div,
-            _ => panic!("Unexpected string '{}'", str),
+    fn parse(str: &str) -> Option<Self> {
+        match str {
+            "+" => Some(Op::Plus),
+            "-" => Some(Op::Minus),
+            "*" => Some(Op::Mul),
+            "/" => Some(Op::Div),
+            _ => None,
         }
     }
 }
 
 #[derive(Clone)]
-enum Expr {
+pub enum Expr {
     Number(i64),
     Math(String, Op, String),
 }
 
 impl Expr {
-    fn parse(line: &str) -> Self {
-        let line = line.trim();
+    fn parse(tokens: &mut Tokens) -> Result<Self, ParseError> {
+        let lhs = tokens.word("number or monkey name")?;
+        if let Ok(n) = lhs.parse::<i64>() {
+            return Ok(Expr::Number(n));
+        }
+
+        let op = tokens.parse_with("one of '+', '-', '*', '/'", Op::parse)?;
+        let rhs = tokens.word("monkey name")?;
 
-        if let Ok(n) = line.parse::<i64>() {
-            return Expr::Number(n);
+        Ok(Expr::Math(lhs.to_string(), op, rhs.to_string()))
+    }
+}
+
+fn parse_monkeys(content: &str) -> Result<HashMap<String, Expr>, ParseError> {
+    let monkeys = parse_lines(content, |line| {
+        let mut tokens = Tokens::new(line);
+        let name = tokens.parse_with("'<name>:'", |word| word.strip_suffix(':'))?;
+        let expr = Expr::parse(&mut tokens)?;
+        tokens.finish()?;
+        Ok((name.to_string(), expr))
+    })?;
+    let lines: Vec<&str> = content.lines().collect();
+    let error = |idx: usize, word: &str, expected: &str| {
+        let line = lines[idx];
+        let token = line.split_whitespace().find(|&w| w == word).unwrap();
+        ParseError::at_token(line, token, expected).at_line(idx + 1, line)
+    };
+
+    let mut name2expr = HashMap::new();
+    for (idx, (name, expr)) in monkeys.iter().enumerate() {
+        if name2expr.insert(name.clone(), expr.clone()).is_some() {
+            return Err(error(idx, &format!("{}:", name), "name of another monkey"));
+        }
+    }
+    // The monkeys waited for yell too
+    for (idx, (_, expr)) in monkeys.iter().enumerate() {
+        if let Expr::Math(lhs, _, rhs) = expr {
+            for operand in [lhs, rhs] {
+                if !name2expr.contains_key(operand) {
+                    return Err(error(idx, operand, "name of a monkey of the list"));
+                }
+            }
         }
+    }
+    if !name2expr.contains_key("root") {
+        let idx = name2expr.len().max(1) - 1;
+        let last = lines.get(idx).copied().unwrap_or("");
+        return Err(ParseError::at_end(last, "monkey 'root'").at_line(idx + 1, last));
+    }
+
+    Ok(name2expr)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Expr>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(content)
+    }
+
+    fn part1(name2expr: &Self::Input) -> impl Display {
+        task_1(name2expr)
+    }
 
-        let mut splitter = line.split(" ");
+    fn part2(name2expr: &Self::Input) -> impl Display {
+        task_2(name2expr)
+    }
+}
 
-        let lhs_name = splitter.next().unwrap().to_string();
-        assert!(lhs_name.len() > 0);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+    #[test]
+    fn part1_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part1(&input).to_string(), "152");
+    }
 
-        let op = Op::parse(splitter.next().unwrap());
+    #[test]
+    fn part2_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part2(&input).to_string(), "301");
+    }
 
-        let rhs_name = splitter.next().unwrap().to_string();
-        assert!(rhs_name.len() > 0);
+    #[test]
+    fn rejects_malformed_monkeys() {
+        let parse_err = |list: &str| Day21::parse(list).err().unwrap();
+        let err = parse_err(&EXAMPLE.replace("dbpl: 5", "dbpl 5"));
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 1, "'<name>:'")
+        );
+        let err = parse_err(&EXAMPLE.replace("humn - dvpt", "humn ^ dvpt"));
+        assert_eq!((err.line, err.column), (5, 12));
+        let err = parse_err(&EXAMPLE.replace("ljgn: 2", "dvpt: 2"));
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (9, 1, "name of another monkey")
+        );
+        let err = parse_err(&EXAMPLE.replace("drzm * dbpl", "drzm * dbpx"));
+        assert_eq!((err.line, err.column), (10, 14));
+        assert_eq!(err.expected, "name of a monkey of the list");
+        let err = parse_err(&EXAMPLE.replace("root", "toor"));
+        assert_eq!((err.line, err.expected.as_str()), (15, "monkey 'root'"));
+    }
 
-        Expr::Math(lhs_name, op, rhs_name)
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day21>(EXAMPLE);
     }
 }
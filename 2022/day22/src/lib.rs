@@ -0,0 +1,1146 @@
+use aoc_core::{ParseError, Simulation, Solution, Visualize};
+use grid::Grid;
+#[cfg(any(test, feature = "gen"))]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(any(test, feature = "gen"))]
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use tracing::{debug, trace};
+
+type Vec2 = geom::Vec2<i32>;
+#[cfg(any(test, feature = "gen"))]
+type Vec3 = geom::Vec3<i32>;
+
+fn task_1(field: &Grid<Cell>, actions: &[Action]) -> i32 {
+    let x = (field.row(0).iter().position(|c| *c == Cell::Empty).unwrap()) as i32;
+    let y = 0;
+
+    let env = Env::new(field.clone(), Walker::new(Vec2 { x, y }));
+    let mut journey = Journey::new(env, actions);
+    while journey.step() {}
+
+    calc_score_1(journey.env.walker())
+}
+
+fn task_2(field: &Grid<Cell>, actions: &[Action]) -> i32 {
+    let env = Env2::new(field.clone(), edge_length(field) as i32);
+    let mut journey = Journey::new(env, actions);
+    while journey.step() {}
+
+    calc_score_1(journey.env.walker())
+}
+
+// Number of cells along an edge of the cube the map folds into
+fn edge_length(field: &Grid<Cell>) -> usize {
+    let n_cells: usize = field.iter().filter(|(_, &c)| c != Cell::Out).count();
+
+    const N_FACES: usize = 6;
+    if !n_cells.is_multiple_of(N_FACES) {
+        panic!("Such a number of cells cannot be on the surface of cube");
+    }
+    let n_cells_per_face = n_cells / N_FACES;
+    debug!("Number of cells per face: {}", n_cells_per_face);
+
+    let n_cells_per_edge: usize = (n_cells_per_face as f32).sqrt() as usize;
+    if n_cells_per_edge * n_cells_per_edge != n_cells_per_face {
+        panic!("The area of a face is not the square of an integer");
+    }
+
+    if !field.height().is_multiple_of(n_cells_per_edge) {
+        panic!(
+            "Map height[={}] must be a multiple of the {}",
+            field.height(),
+            n_cells_per_edge
+        )
+    }
+    if !field.width().is_multiple_of(n_cells_per_edge) {
+        panic!(
+            "Map width[={}] must be a multiple of the {}",
+            field.width(),
+            n_cells_per_edge
+        );
+    }
+
+    n_cells_per_edge
+}
+
+type Pos = Vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Face {
+    pos: Pos, // position on fiels
+    orientation: Vec2,
+}
+
+impl Face {
+    fn no_pos() -> Vec2 {
+        Vec2 { x: -1, y: -1 }
+    }
+}
+
+impl Default for Face {
+    fn default() -> Self {
+        Self {
+            pos: Self::no_pos(),
+            orientation: Vec2 { x: 0, y: 0 },
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Cube {
+    back: Face,
+    left: Face,
+    front: Face,
+    right: Face,
+    top: Face,
+    bottom: Face,
+}
+
+impl Cube {
+    fn rot_y90_cw(&self) -> Self {
+        let mut obj = Self {
+            back: self.back,
+            left: self.bottom,
+            front: self.front,
+            right: self.top,
+            top: self.left,
+            bottom: self.right,
+        };
+
+        obj.back.orientation = obj.back.orientation.rot_ccw();
+
+        obj.left.orientation = obj.left.orientation.rot_cw();
+        obj.front.orientation = obj.front.orientation.rot_cw();
+        obj.right.orientation = obj.right.orientation.rot_cw();
+        obj.top.orientation = obj.top.orientation.rot_cw();
+        obj.bottom.orientation = obj.bottom.orientation.rot_cw();
+
+        obj
+    }
+
+    fn rot_x90_cw(&self) -> Self {
+        let mut obj = Self {
+            back: self.top,
+            left: self.left,
+            front: self.bottom,
+            right: self.right,
+            top: self.front,
+            bottom: self.back,
+        };
+
+        obj.back.orientation = obj.back.orientation.rot_cw().rot_cw();
+        obj.bottom.orientation = obj.bottom.orientation.rot_cw().rot_cw();
+        obj.left.orientation = obj.left.orientation.rot_ccw();
+        obj.right.orientation = obj.right.orientation.rot_cw();
+
+        obj
+    }
+
+    fn rot_x90_ccw(&self) -> Self {
+        Self { ..*self }.rot_x90_cw().rot_x90_cw().rot_x90_cw()
+    }
+
+    fn rot_z90_cw(&self) -> Self {
+        let mut obj = Self {
+            back: self.left,
+            left: self.front,
+            front: self.right,
+            right: self.back,
+            top: self.top,
+            bottom: self.bottom,
+        };
+
+        obj.top.orientation = obj.top.orientation.rot_cw();
+        obj.bottom.orientation = obj.bottom.orientation.rot_ccw();
+
+        obj
+    }
+
+    fn rot_z90_ccw(&self) -> Self {
+        Self { ..*self }.rot_z90_cw().rot_z90_cw().rot_z90_cw()
+    }
+
+    fn fill(mut self, pos: Vec2, field: &Grid<Cell>, step: i32) -> Self {
+        if self.front.pos != Face::no_pos() {
+            return self;
+        }
+
+        self.front.pos = pos;
+        self.front.orientation = Vec2 { x: 0, y: -1 };
+
+        let is_on_cube = |pos: Vec2| field.get(pos).is_some_and(|&c| c != Cell::Out);
+
+        let neib = pos + Vec2 { x: 0, y: -step };
+        if is_on_cube(neib) {
+            self = self.rot_x90_ccw();
+            self = self.fill(neib, field, step);
+            self = self.rot_x90_cw();
+        }
+
+        let neib = pos + Vec2 { x: 0, y: step };
+        if is_on_cube(neib) {
+            self = self.rot_x90_cw();
+            self = self.fill(neib, field, step);
+            self = self.rot_x90_ccw();
+        }
+
+        let neib = pos + Vec2 { x: step, y: 0 };
+        if is_on_cube(neib) {
+            self = self.rot_z90_cw();
+            self = self.fill(neib, field, step);
+            self = self.rot_z90_ccw();
+        }
+
+        let neib = pos + Vec2 { x: -step, y: 0 };
+        if is_on_cube(neib) {
+            self = self.rot_z90_ccw();
+            self = self.fill(neib, field, step);
+            self = self.rot_z90_cw();
+        }
+
+        self
+    }
+
+    fn from_field(field: &Grid<Cell>, step: i32) -> Self {
+        let x = field.row(0).iter().position(|c| *c != Cell::Out).unwrap() as i32;
+        let pos = Vec2 { x, y: 0 };
+        Cube::default().fill(pos, field, step)
+    }
+}
+
+// Placement of a face of a net on the unit cube: the corner its top-left cell lands on, the
+// directions of its rows and columns and its outward normal
+#[cfg(any(test, feature = "gen"))]
+#[derive(Debug, Clone, Copy)]
+struct Fold {
+    corner: Vec3,
+    right: Vec3,
+    down: Vec3,
+    normal: Vec3,
+}
+
+// Folds a net given by the positions of its faces (in faces), or returns `None` if the faces
+// overlap, i.e. the net is not a net of a cube
+#[cfg(any(test, feature = "gen"))]
+fn fold_net(faces: &[Vec2]) -> Option<HashMap<Vec2, Fold>> {
+    let first = Fold {
+        corner: Vec3::zero(),
+        right: Vec3 { x: 1, y: 0, z: 0 },
+        down: Vec3 { x: 0, y: 1, z: 0 },
+        normal: Vec3 { x: 0, y: 0, z: -1 },
+    };
+    let mut folds = HashMap::from([(faces[0], first)]);
+    let mut stack = vec![faces[0]];
+
+    while let Some(face) = stack.pop() {
+        let f = folds[&face];
+        let neighbors = [
+            (
+                Vec2::east(),
+                Fold {
+                    corner: f.corner + f.right,
+                    right: -f.normal,
+                    normal: f.right,
+                    ..f
+                },
+            ),
+            (
+                Vec2::west(),
+                Fold {
+                    corner: f.corner - f.normal,
+                    right: f.normal,
+                    normal: -f.right,
+                    ..f
+                },
+            ),
+            (
+                Vec2::south(),
+                Fold {
+                    corner: f.corner + f.down,
+                    down: -f.normal,
+                    normal: f.down,
+                    ..f
+                },
+            ),
+            (
+                Vec2::north(),
+                Fold {
+                    corner: f.corner - f.normal,
+                    down: f.normal,
+                    normal: -f.down,
+                    ..f
+                },
+            ),
+        ];
+        for (dir, fold) in neighbors {
+            let neib = face + dir;
+            if faces.contains(&neib) && !folds.contains_key(&neib) {
+                folds.insert(neib, fold);
+                stack.push(neib);
+            }
+        }
+    }
+
+    let normals: HashSet<Vec3> = folds.values().map(|f| f.normal).collect();
+    (normals.len() == 6).then_some(folds)
+}
+
+// How the walker gets around the map: wrapping around its edges or over the faces of the cube
+trait Walk {
+    fn field(&self) -> &Grid<Cell>;
+    fn walker(&self) -> &Walker;
+    fn walker_mut(&mut self) -> &mut Walker;
+    fn step_walker(&mut self) -> bool;
+}
+
+/// The walker following the path, one tile or turn at a time, leaving a trail of arrows.
+struct Journey<'a, E> {
+    env: E,
+    actions: &'a [Action],
+    next: usize, // index of the action in progress
+    done: i32,   // tiles already walked of it
+    canvas: Grid<char>,
+}
+
+impl<'a, E: Walk> Journey<'a, E> {
+    fn new(env: E, actions: &'a [Action]) -> Self {
+        let canvas = env.field().map(|c| match *c {
+            Cell::Out => ' ',
+            Cell::Empty => '.',
+            Cell::Wall => '#',
+        });
+        let mut journey = Self {
+            env,
+            actions,
+            next: 0,
+            done: 0,
+            canvas,
+        };
+        journey.paint_walker();
+        journey
+    }
+
+    fn paint_walker(&mut self) {
+        let walker = self.env.walker();
+        self.canvas[walker.pos] = match walker.dir {
+            Vec2 { x: 1, y: 0 } => '>',
+            Vec2 { x: 0, y: 1 } => 'v',
+            Vec2 { x: -1, y: 0 } => '<',
+            Vec2 { x: 0, y: -1 } => '^',
+            _ => panic!("Unexpected dir: {:?}", walker.dir),
+        };
+    }
+}
+
+impl<E: Walk> Simulation for Journey<'_, E> {
+    fn step(&mut self) -> bool {
+        let Some(&action) = self.actions.get(self.next) else {
+            return false;
+        };
+        if self.done == 0 {
+            trace!("{:?}", action);
+        }
+
+        let action_done = match action {
+            Action::Move(steps) => {
+                let moved = self.env.step_walker();
+                self.done += 1;
+                !moved || self.done == steps
+            }
+            Action::Rotate90CW => {
+                let walker = self.env.walker_mut();
+                walker.dir = walker.dir.rot_cw();
+                true
+            }
+            Action::Rotate90CCW => {
+                let walker = self.env.walker_mut();
+                walker.dir = walker.dir.rot_ccw();
+                true
+            }
+        };
+        self.paint_walker();
+
+        if action_done {
+            self.next += 1;
+            self.done = 0;
+            trace!("\n{}", self.canvas);
+        }
+        true
+    }
+
+    fn render(&self) -> String {
+        const H: i32 = 40;
+        const W: i32 = 100;
+        let pos = self.env.walker().pos;
+        let top = (pos.y - H / 2).clamp(0, (self.canvas.height() as i32 - H).max(0));
+        let left = (pos.x - W / 2).clamp(0, (self.canvas.width() as i32 - W).max(0));
+
+        let mut window = format!("action {}/{}\n", self.next, self.actions.len());
+        for y in top..(top + H).min(self.canvas.height() as i32) {
+            let row: String = (left..(left + W).min(self.canvas.width() as i32))
+                .map(|x| self.canvas[Vec2 { x, y }])
+                .collect();
+            window += row.trim_end();
+            window.push('\n');
+        }
+        window
+    }
+}
+
+struct Env2 {
+    field: Grid<Cell>,
+    cube: Cube,
+    step: i32,
+    walker: Walker,
+}
+
+impl Env2 {
+    fn new(field: Grid<Cell>, step: i32) -> Self {
+        let cube = Cube::from_field(&field, step);
+
+        let x = field.row(0).iter().position(|c| *c != Cell::Out).unwrap() as i32;
+        let walker = Walker::new(Vec2 { x, y: 0 });
+
+        Self {
+            field,
+            cube,
+            step,
+            walker,
+        }
+    }
+
+    fn cell_at_pos(&self, pos: Vec2) -> Cell {
+        self.field.get(pos).copied().unwrap_or(Cell::Out)
+    }
+
+    fn rotate_cube(&mut self, front_face_pos: Pos) {
+        if self.cube.top.pos == front_face_pos {
+            self.cube = self.cube.rot_x90_ccw();
+        } else if self.cube.bottom.pos == front_face_pos {
+            self.cube = self.cube.rot_x90_cw();
+        } else {
+            let mut counter = 0;
+            while self.cube.front.pos != front_face_pos {
+                self.cube = self.cube.rot_z90_cw();
+                counter += 1;
+                if counter > 4 {
+                    panic!("Face not found on cube");
+                }
+            }
+        }
+
+        let up_orientation = Vec2 { x: 0, y: -1 };
+        while self.cube.front.orientation != up_orientation {
+            self.cube = self.cube.rot_y90_cw();
+        }
+    }
+}
+
+/// The map, a blank line and the path, which may be split over several lines.
+fn parse_notes(content: &str) -> Result<(Grid<Cell>, Vec<Action>), ParseError> {
+    let lines: Vec<&str> = content.trim_end_matches(['\r', '\n']).lines().collect();
+    let Some(blank) = lines.iter().position(|line| line.trim().is_empty()) else {
+        let last = lines.last().copied().unwrap_or("");
+        let err = ParseError::at_end(last, "blank line before the path");
+        return Err(err.at_line(lines.len().max(1), last));
+    };
+
+    for (idx, line) in lines[..blank].iter().enumerate() {
+        if let Some(tile) = line.matches(|ch| !matches!(ch, ' ' | '.' | '#')).next() {
+            let err = ParseError::at_token(line, tile, "' ', '.' or '#'");
+            return Err(err.at_line(idx + 1, line));
+        }
+    }
+    let first = lines[0];
+    if !first.contains('.') {
+        return Err(ParseError::at_end(first, "open tile in the top row").at_line(1, first));
+    }
+
+    let mut actions = Vec::new();
+    for (idx, line) in lines.iter().enumerate().skip(blank + 1) {
+        actions.extend(parse_actions(line).map_err(|err| err.at_line(idx + 1, line))?);
+    }
+    if actions.is_empty() {
+        let err = ParseError::at_end(lines[blank], "path");
+        return Err(err.at_line(blank + 1, lines[blank]));
+    }
+
+    let map = lines[..blank].join("\n");
+    Ok((Grid::parse(&map, Cell::from_char), actions))
+}
+
+fn parse_actions(line: &str) -> Result<Vec<Action>, ParseError> {
+    let mut actions = Vec::new();
+
+    let mut rest = line.trim();
+    while !rest.is_empty() {
+        let (action, tail) = parse_action(line, rest)?;
+        rest = tail;
+        actions.push(action);
+    }
+
+    Ok(actions)
+}
+
+/// First action of `text`, a slice of `line`, and the text after it.
+fn parse_action<'a>(line: &str, text: &'a str) -> Result<(Action, &'a str), ParseError> {
+    if let Some(rest) = text.strip_prefix('L') {
+        return Ok((Action::Rotate90CCW, rest));
+    }
+    if let Some(rest) = text.strip_prefix('R') {
+        return Ok((Action::Rotate90CW, rest));
+    }
+
+    let n_digits = text
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(text.len());
+    let (number, rest) = text.split_at(n_digits);
+    match number.parse() {
+        Ok(n) => Ok((Action::Move(n), rest)),
+        Err(_) => {
+            let token = match n_digits {
+                0 => &text[..text.chars().next().map_or(0, char::len_utf8)],
+                _ => number,
+            };
+            Err(ParseError::at_token(
+                line,
+                token,
+                "number of tiles, 'L' or 'R'",
+            ))
+        }
+    }
+}
+
+fn calc_score_1(walker: &Walker) -> i32 {
+    let dir_score = match walker.dir {
+        Vec2 { x: 1, y: 0 } => 0,
+        Vec2 { x: 0, y: 1 } => 1,
+        Vec2 { x: -1, y: 0 } => 2,
+        Vec2 { x: 0, y: -1 } => 3,
+        _ => panic!("Unexpected dir: {:?}", walker.dir),
+    };
+    1000 * (walker.pos.y + 1) + 4 * (walker.pos.x + 1) + dir_score
+}
+
+struct Env {
+    field: Grid<Cell>,
+
+    walker: Walker,
+}
+
+impl Env {
+    fn new(field: Grid<Cell>, walker: Walker) -> Self {
+        Self { field, walker }
+    }
+
+    fn cell_at_pos(&self, pos: Vec2) -> Cell {
+        self.field.get(pos).copied().unwrap_or(Cell::Out)
+    }
+
+    fn wrap(&self, mut pos: Vec2) -> Vec2 {
+        if pos.y < 0 {
+            pos.y = (self.field.height() as i32) - 1;
+        }
+        if (pos.y as usize) >= self.field.height() {
+            pos.y = 0;
+        }
+
+        if pos.x < 0 {
+            pos.x = (self.field.width() as i32) - 1;
+        }
+        if (pos.x as usize) >= self.field.width() {
+            pos.x = 0;
+        }
+
+        pos
+    }
+}
+
+impl Walk for Env {
+    fn field(&self) -> &Grid<Cell> {
+        &self.field
+    }
+
+    fn walker(&self) -> &Walker {
+        &self.walker
+    }
+
+    fn walker_mut(&mut self) -> &mut Walker {
+        &mut self.walker
+    }
+
+    // Moves the walker one tile forward, wrapping around to the other side of the map if
+    // needed; returns `false` if a wall is in the way
+    fn step_walker(&mut self) -> bool {
+        let mut nxt_pos = self.walker.pos;
+        loop {
+            nxt_pos = self.wrap(nxt_pos + self.walker.dir);
+            match self.cell_at_pos(nxt_pos) {
+                Cell::Out => continue,
+                Cell::Empty => break,
+                Cell::Wall => return false,
+            }
+        }
+        trace!("walker at {}", nxt_pos);
+        self.walker.pos = nxt_pos;
+        true
+    }
+}
+
+impl Walk for Env2 {
+    fn field(&self) -> &Grid<Cell> {
+        &self.field
+    }
+
+    fn walker(&self) -> &Walker {
+        &self.walker
+    }
+
+    fn walker_mut(&mut self) -> &mut Walker {
+        &mut self.walker
+    }
+
+    // Moves the walker one tile forward, over an edge onto the adjacent face of the cube if
+    // needed; returns `false` if a wall is in the way
+    fn step_walker(&mut self) -> bool {
+        trace!("walker at {}", self.walker.pos);
+
+        let mut nxt_pos = self.walker.pos + self.walker.dir;
+        let mut nxt_dir = self.walker.dir;
+
+        match self.cell_at_pos(nxt_pos) {
+            Cell::Out => {
+                let face_pos = Vec2 {
+                    x: (self.walker.pos.x / self.step) * self.step,
+                    y: (self.walker.pos.y / self.step) * self.step,
+                };
+
+                self.rotate_cube(face_pos);
+
+                trace!("{:#?}", self.cube);
+
+                let face_x = self.walker.pos.x % self.step;
+                let face_y = self.walker.pos.y % self.step;
+
+                let nxt_pos_on_neib_face;
+
+                match self.walker.dir {
+                    Vec2 { x: 1, y: 0 } => match self.cube.right.orientation {
+                        Vec2 { x: 0, y: -1 } => {
+                            nxt_pos_on_neib_face = self.cube.right.pos + Vec2 { x: 0, y: face_y };
+                        }
+                        Vec2 { x: -1, y: 0 } => {
+                            nxt_pos_on_neib_face = self.cube.right.pos
+                                + Vec2 {
+                                    x: self.step - 1 - face_y,
+                                    y: 0,
+                                };
+                            nxt_dir = Vec2 { x: 0, y: 1 };
+                        }
+                        Vec2 { x: 1, y: 0 } => {
+                            nxt_pos_on_neib_face = self.cube.right.pos
+                                + Vec2 {
+                                    x: face_y,
+                                    y: self.step - 1,
+                                };
+                            nxt_dir = Vec2 { x: 0, y: -1 };
+                        }
+                        Vec2 { x: 0, y: 1 } => {
+                            nxt_pos_on_neib_face = self.cube.right.pos
+                                + Vec2 {
+                                    x: self.step - 1,
+                                    y: self.step - 1 - face_y,
+                                };
+                            nxt_dir = Vec2 { x: -1, y: 0 };
+                        }
+                        _ => unreachable!(),
+                    },
+                    Vec2 { x: 0, y: 1 } => match self.cube.bottom.orientation {
+                        Vec2 { x: 0, y: -1 } => {
+                            nxt_pos_on_neib_face = self.cube.bottom.pos + Vec2 { x: face_x, y: 0 };
+                        }
+                        Vec2 { x: -1, y: 0 } => {
+                            nxt_pos_on_neib_face = self.cube.bottom.pos
+                                + Vec2 {
+                                    x: self.step - 1,
+                                    y: face_x,
+                                };
+                            nxt_dir = Vec2 { x: -1, y: 0 };
+                        }
+                        Vec2 { x: 1, y: 0 } => {
+                            nxt_pos_on_neib_face = self.cube.bottom.pos
+                                + Vec2 {
+                                    x: 0,
+                                    y: self.step - 1 - face_x,
+                                };
+                            nxt_dir = Vec2 { x: 1, y: 0 };
+                        }
+                        Vec2 { x: 0, y: 1 } => {
+                            nxt_pos_on_neib_face = self.cube.bottom.pos
+                                + Vec2 {
+                                    x: self.step - 1 - face_x,
+                                    y: self.step - 1,
+                                };
+                            nxt_dir = Vec2 { x: 0, y: -1 };
+                        }
+                        _ => unreachable!(),
+                    },
+                    Vec2 { x: -1, y: 0 } => match self.cube.left.orientation {
+                        Vec2 { x: 0, y: -1 } => {
+                            nxt_pos_on_neib_face = self.cube.left.pos
+                                + Vec2 {
+                                    x: self.step - 1,
+                                    y: face_y,
+                                };
+                        }
+                        Vec2 { x: -1, y: 0 } => {
+                            nxt_pos_on_neib_face = self.cube.left.pos
+                                + Vec2 {
+                                    x: self.step - 1 - face_y,
+                                    y: self.step - 1,
+                                };
+                            nxt_dir = Vec2 { x: 0, y: -1 };
+                        }
+                        Vec2 { x: 1, y: 0 } => {
+                            nxt_pos_on_neib_face = self.cube.left.pos + Vec2 { x: face_y, y: 0 };
+                            nxt_dir = Vec2 { x: 0, y: 1 };
+                        }
+                        Vec2 { x: 0, y: 1 } => {
+                            nxt_pos_on_neib_face = self.cube.left.pos
+                                + Vec2 {
+                                    x: 0,
+                                    y: self.step - 1 - face_y,
+                                };
+                            nxt_dir = Vec2 { x: 1, y: 0 };
+                        }
+                        _ => unreachable!(),
+                    },
+                    Vec2 { x: 0, y: -1 } => match self.cube.top.orientation {
+                        Vec2 { x: 0, y: -1 } => {
+                            nxt_pos_on_neib_face = self.cube.top.pos
+                                + Vec2 {
+                                    x: face_x,
+                                    y: self.step - 1,
+                                };
+                        }
+                        Vec2 { x: -1, y: 0 } => {
+                            nxt_pos_on_neib_face = self.cube.top.pos + Vec2 { x: 0, y: face_x };
+                            nxt_dir = Vec2 { x: 1, y: 0 };
+                        }
+                        Vec2 { x: 1, y: 0 } => {
+                            nxt_pos_on_neib_face = self.cube.top.pos
+                                + Vec2 {
+                                    x: self.step - 1,
+                                    y: self.step - 1 - face_x,
+                                };
+                            nxt_dir = Vec2 { x: -1, y: 0 };
+                        }
+                        Vec2 { x: 0, y: 1 } => {
+                            nxt_pos_on_neib_face = self.cube.top.pos
+                                + Vec2 {
+                                    x: self.step - 1 - face_x,
+                                    y: 0,
+                                };
+                            nxt_dir = Vec2 { x: 0, y: 1 };
+                        }
+                        _ => unreachable!(),
+                    },
+                    _ => panic!("Unexpected dir: {:?}", self.walker.dir),
+                }
+
+                if self.cell_at_pos(nxt_pos_on_neib_face) == Cell::Wall {
+                    return false;
+                }
+
+                nxt_pos = nxt_pos_on_neib_face;
+            }
+            Cell::Empty => (),
+            Cell::Wall => return false,
+        }
+
+        self.walker.pos = nxt_pos;
+        self.walker.dir = nxt_dir;
+        true
+    }
+}
+
+#[derive(Debug)]
+struct Walker {
+    pos: Vec2,
+    dir: Vec2,
+}
+
+impl Walker {
+    fn new(pos: Vec2) -> Self {
+        // By default walker is looking towards the east
+        Self {
+            pos,
+            dir: Vec2 { x: 1, y: 0 },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Action {
+    Move(i32),
+    Rotate90CW,
+    Rotate90CCW,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Cell {
+    Out,
+    Empty,
+    Wall,
+}
+
+impl Cell {
+    fn from_char(ch: char) -> Self {
+        match ch {
+            ' ' => Cell::Out,
+            '.' => Cell::Empty,
+            '#' => Cell::Wall,
+            _ => panic!("Invalid char to form cell: '{}'", ch),
+        }
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Grid<Cell>, Vec<Action>);
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_notes(content)
+    }
+
+    fn part1((field, actions): &Self::Input) -> impl Display {
+        task_1(field, actions)
+    }
+
+    fn part2((field, actions): &Self::Input) -> impl Display {
+        task_2(field, actions)
+    }
+}
+
+impl Visualize for Day22 {
+    fn simulation((field, actions): &Self::Input, part: u32) -> Box<dyn Simulation + '_> {
+        if part == 1 {
+            let x = (field.row(0).iter().position(|c| *c == Cell::Empty).unwrap()) as i32;
+            let env = Env::new(field.clone(), Walker::new(Vec2 { x, y: 0 }));
+            Box::new(Journey::new(env, actions))
+        } else {
+            let env = Env2::new(field.clone(), edge_length(field) as i32);
+            Box::new(Journey::new(env, actions))
+        }
+    }
+}
+
+/// Generates a random cube net with walls on its faces followed by a path.
+#[cfg(any(test, feature = "gen"))]
+pub fn generate(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // Grow random hexominoes until one folds into a cube
+    let mut faces = loop {
+        let mut faces = vec![Vec2::zero()];
+        while faces.len() < 6 {
+            let face =
+                faces[rng.random_range(0..faces.len())] + Vec2::dirs4()[rng.random_range(0..4)];
+            if !faces.contains(&face) {
+                faces.push(face);
+            }
+        }
+        if fold_net(&faces).is_some() {
+            break faces;
+        }
+    };
+    let min_x = faces.iter().map(|f| f.x).min().unwrap();
+    let min_y = faces.iter().map(|f| f.y).min().unwrap();
+    for face in &mut faces {
+        *face -= Vec2 { x: min_x, y: min_y };
+    }
+    let width = faces.iter().map(|f| f.x).max().unwrap() + 1;
+    let height = faces.iter().map(|f| f.y).max().unwrap() + 1;
+
+    let step = rng.random_range(1..=6);
+    let mut rows = Vec::new();
+    for y in 0..height * step {
+        let mut row = String::new();
+        for x in 0..width * step {
+            let face = Vec2 {
+                x: x / step,
+                y: y / step,
+            };
+            row.push(if !faces.contains(&face) {
+                ' '
+            } else if (y == 0 && row.trim().is_empty()) || !rng.random_bool(0.2) {
+                '.'
+            } else {
+                '#'
+            });
+        }
+        rows.push(row.trim_end().to_string());
+    }
+
+    let mut path = rng.random_range(1..=4 * step).to_string();
+    for _ in 0..rng.random_range(0..=20) {
+        path.push(if rng.random_bool(0.5) { 'R' } else { 'L' });
+        path += &rng.random_range(1..=4 * step).to_string();
+    }
+
+    format!("{}\n\n{}", rows.join("\n"), path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn turn(dir: Vec2, action: Action) -> Vec2 {
+        match action {
+            Action::Rotate90CW => Vec2 {
+                x: -dir.y,
+                y: dir.x,
+            },
+            Action::Rotate90CCW => Vec2 {
+                x: dir.y,
+                y: -dir.x,
+            },
+            Action::Move(_) => dir,
+        }
+    }
+
+    // Steps cell by cell, wrapping around by walking back to the opposite edge of the map
+    fn naive_1(field: &Grid<Cell>, actions: &[Action]) -> i32 {
+        let cell = |pos: Vec2| field.get(pos).copied().unwrap_or(Cell::Out);
+        let x = field.row(0).iter().position(|c| *c != Cell::Out).unwrap() as i32;
+        let mut pos = Vec2 { x, y: 0 };
+        let mut dir = Vec2::east();
+
+        for &action in actions {
+            let Action::Move(steps) = action else {
+                dir = turn(dir, action);
+                continue;
+            };
+            for _ in 0..steps {
+                let mut nxt = pos + dir;
+                if cell(nxt) == Cell::Out {
+                    nxt = pos;
+                    while cell(nxt - dir) != Cell::Out {
+                        nxt -= dir;
+                    }
+                }
+                if cell(nxt) == Cell::Wall {
+                    break;
+                }
+                pos = nxt;
+            }
+        }
+
+        calc_score_1(&Walker { pos, dir })
+    }
+
+    // Walks over the folded cube in 3D: a cell is a point of [0, step)^3 together with the
+    // normal of its face, and a step off a face keeps the point but tips the walker over the edge
+    fn naive_2(field: &Grid<Cell>, actions: &[Action]) -> i32 {
+        let n_cells = field.iter().filter(|(_, &c)| c != Cell::Out).count();
+        let step = ((n_cells / 6) as f64).sqrt() as i32;
+        let face_of = |pos: Vec2| Vec2 {
+            x: pos.x / step,
+            y: pos.y / step,
+        };
+
+        let faces: Vec<Vec2> = field
+            .iter()
+            .filter(|(pos, &c)| c != Cell::Out && pos.x % step == 0 && pos.y % step == 0)
+            .map(|(pos, _)| face_of(pos))
+            .collect();
+        let folds = fold_net(&faces).unwrap();
+        let point = |pos: Vec2| {
+            let f = folds[&face_of(pos)];
+            f.corner * (step - 1) + f.right * (pos.x % step) + f.down * (pos.y % step)
+        };
+        let cells: HashMap<(Vec3, Vec3), Vec2> = field
+            .iter()
+            .filter(|(_, &c)| c != Cell::Out)
+            .map(|(pos, _)| ((point(pos), folds[&face_of(pos)].normal), pos))
+            .collect();
+        let on_cube = |p: Vec3| [p.x, p.y, p.z].iter().all(|c| (0..step).contains(c));
+
+        let x = field.row(0).iter().position(|c| *c != Cell::Out).unwrap() as i32;
+        let mut pos = Vec2 { x, y: 0 };
+        let mut dir = Vec2::east();
+
+        for &action in actions {
+            let Action::Move(steps) = action else {
+                dir = turn(dir, action);
+                continue;
+            };
+            for _ in 0..steps {
+                let f = folds[&face_of(pos)];
+                let forward = f.right * dir.x + f.down * dir.y;
+                let (p, normal, forward) = match point(pos) + forward {
+                    p if on_cube(p) => (p, f.normal, forward),
+                    _ => (point(pos), forward, -f.normal),
+                };
+
+                let nxt = cells[&(p, normal)];
+                if field[nxt] == Cell::Wall {
+                    break;
+                }
+                let g = folds[&face_of(nxt)];
+                dir = if forward == g.right {
+                    Vec2::east()
+                } else if forward == -g.right {
+                    Vec2::west()
+                } else if forward == g.down {
+                    Vec2::south()
+                } else {
+                    Vec2::north()
+                };
+                pos = nxt;
+            }
+        }
+
+        calc_score_1(&Walker { pos, dir })
+    }
+
+    const EXAMPLE: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+    #[test]
+    fn part1_example() {
+        let input = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(Day22::part1(&input).to_string(), "6032");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(Day22::part2(&input).to_string(), "5031");
+    }
+
+    fn labeled_cube() -> Cube {
+        let face = |x| Face {
+            pos: Pos::new(x, 0),
+            orientation: Vec2::north(),
+        };
+        Cube {
+            back: face(0),
+            left: face(1),
+            front: face(2),
+            right: face(3),
+            top: face(4),
+            bottom: face(5),
+        }
+    }
+
+    #[test]
+    fn cube_rotations_move_faces() {
+        let cube = labeled_cube();
+
+        let rotated = cube.rot_x90_cw();
+        assert_eq!(rotated.top.pos, cube.front.pos);
+        assert_eq!(rotated.front.pos, cube.bottom.pos);
+
+        let rotated = cube.rot_z90_cw();
+        assert_eq!(rotated.front.pos, cube.right.pos);
+        assert_eq!(rotated.left.pos, cube.front.pos);
+        assert_eq!(rotated.top.pos, cube.top.pos);
+
+        let rotated = cube.rot_y90_cw();
+        assert_eq!(rotated.front.pos, cube.front.pos);
+        assert_eq!(rotated.top.pos, cube.left.pos);
+        assert_eq!(rotated.front.orientation, Vec2::east());
+    }
+
+    #[test]
+    fn cube_rotations_are_cyclic() {
+        let cube = labeled_cube();
+
+        let rotations: [fn(&Cube) -> Cube; 3] =
+            [Cube::rot_x90_cw, Cube::rot_y90_cw, Cube::rot_z90_cw];
+        for rot in rotations {
+            let mut rotated = cube;
+            for _ in 0..4 {
+                rotated = rot(&rotated);
+            }
+            assert_eq!(rotated, cube);
+        }
+
+        assert_eq!(cube.rot_x90_cw().rot_x90_ccw(), cube);
+        assert_eq!(cube.rot_z90_cw().rot_z90_ccw(), cube);
+    }
+
+    #[test]
+    fn rejects_malformed_notes() {
+        let err = Day22::parse("  ..#\n  .x.\n\n10R5").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = Day22::parse("  ..#\n  ...\n\n10R5X").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (4, 5, "number of tiles, 'L' or 'R'")
+        );
+        let err = Day22::parse("  ..#\n  ...").unwrap_err();
+        assert_eq!(err.expected, "blank line before the path");
+        let err = Day22::parse("  ###\n  ...\n\n10").unwrap_err();
+        assert_eq!(err.line, 1);
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day22>(EXAMPLE);
+    }
+
+    #[test]
+    fn journey_leaves_trail() {
+        let (field, actions) = Day22::parse(EXAMPLE).unwrap();
+        let env = Env2::new(field.clone(), edge_length(&field) as i32);
+        let mut journey = Journey::new(env, &actions);
+        // Ten tiles forward end at a wall after two (bumping into it takes a frame too), then
+        // a turn to the right
+        for _ in 0..4 {
+            assert!(journey.step());
+        }
+        assert_eq!(journey.next, 2);
+        assert_eq!(
+            journey.render().lines().take(3).collect::<Vec<_>>(),
+            ["action 2/13", "        >>v#", "        .#.."]
+        );
+    }
+
+    #[test]
+    fn naive_example() {
+        let (field, actions) = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(naive_1(&field, &actions), 6032);
+        assert_eq!(naive_2(&field, &actions), 5031);
+    }
+
+    proptest! {
+        #[test]
+        fn part1_matches_naive(seed in any::<u64>()) {
+            let (field, actions) = Day22::parse(&generate(seed)).unwrap();
+            prop_assert_eq!(task_1(&field, &actions), naive_1(&field, &actions));
+        }
+
+        #[test]
+        fn part2_matches_naive(seed in any::<u64>()) {
+            let (field, actions) = Day22::parse(&generate(seed)).unwrap();
+            prop_assert_eq!(task_2(&field, &actions), naive_2(&field, &actions));
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use aoc_core::{parse_lines, ParseError, Simulation, Solution, Visualize};
+use geom::BBox;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+use tracing::{enabled, trace, Level};
+
+type Vec2 = geom::Vec2<i32>;
+
+fn task_1(elves: &HashSet<Vec2>) -> i32 {
+    const N_ROUNDS: usize = 10;
+
+    let mut grove = Grove::new(elves.clone(), N_ROUNDS);
+    while grove.step() {}
+
+    let bbox = BBox::from_points(grove.elves.iter().copied()).unwrap();
+
+    bbox.area() - (grove.elves.len() as i32)
+}
+
+fn task_2(elves: &HashSet<Vec2>) -> usize {
+    let mut grove = Grove::new(elves.clone(), usize::MAX);
+    while grove.step() {}
+    grove.round
+}
+
+/// Elves spreading out over the grove, one round at a time.
+struct Grove {
+    elves: HashSet<Vec2>,
+    round: usize,
+    n_rounds: usize,
+    settled: bool, // no elf moved in the last round
+}
+
+impl Grove {
+    fn new(elves: HashSet<Vec2>, n_rounds: usize) -> Self {
+        Self {
+            elves,
+            round: 0,
+            n_rounds,
+            settled: false,
+        }
+    }
+}
+
+impl Simulation for Grove {
+    fn step(&mut self) -> bool {
+        if self.settled || self.round == self.n_rounds {
+            return false;
+        }
+
+        let moved = spread(&self.elves, self.round);
+        self.round += 1;
+        match moved {
+            Some(elves) => {
+                self.elves = elves;
+                print_elves(self.round, &self.elves);
+                true
+            }
+            None => {
+                self.settled = true;
+                false
+            }
+        }
+    }
+
+    fn render(&self) -> String {
+        format!("End of round {}\n{}", self.round, draw_elves(&self.elves))
+    }
+}
+
+// Plays a round: returns the new positions of the elves, or `None` if none of them moves
+fn spread(poses: &HashSet<Vec2>, round: usize) -> Option<HashSet<Vec2>> {
+    let north = Vec2::north();
+    let east = Vec2::east();
+    let south = Vec2::south();
+    let west = Vec2::west();
+
+    // making proposes
+    let mut move2count: HashMap<Vec2, usize> = HashMap::new();
+    let mut proposed_move: HashMap<Vec2, Vec2> = HashMap::new();
+    for &pos in poses {
+        let n_pos = pos + north;
+        let e_pos = pos + east;
+        let s_pos = pos + south;
+        let w_pos = pos + west;
+
+        let n = poses.contains(&n_pos);
+        let ne = poses.contains(&(pos + north + east));
+        let e = poses.contains(&e_pos);
+        let se = poses.contains(&(pos + south + east));
+        let s = poses.contains(&s_pos);
+        let sw = poses.contains(&(pos + south + west));
+        let w = poses.contains(&w_pos);
+        let nw = poses.contains(&(pos + north + west));
+
+        let has_neibs = n || ne || e || se || s || sw || w || nw;
+        if !has_neibs {
+            continue;
+        }
+
+        let mut apply_if_true = |cond, move_pos| {
+            if cond {
+                *move2count.entry(move_pos).or_insert(0) += 1;
+                proposed_move.insert(pos, move_pos);
+                return true;
+            }
+            false
+        };
+
+        let choices = [
+            (!(nw || n || ne), n_pos),
+            (!(se || s || sw), s_pos),
+            (!(nw || w || sw), w_pos),
+            (!(ne || e || se), e_pos),
+        ];
+
+        for i in 0..choices.len() {
+            let idx = (round + i) % choices.len();
+            let choice = choices[idx];
+            if apply_if_true(choice.0, choice.1) {
+                break;
+            }
+        }
+    }
+
+    if proposed_move.is_empty() {
+        return None;
+    }
+
+    let mut new_poses = HashSet::new();
+    for &pos in poses {
+        if let Some(mv_pos) = proposed_move.get(&pos) {
+            if *move2count.get(mv_pos).unwrap() == 1 {
+                new_poses.insert(*mv_pos);
+            } else {
+                new_poses.insert(pos);
+            }
+        } else {
+            new_poses.insert(pos);
+        }
+    }
+
+    Some(new_poses)
+}
+
+fn print_elves(round: usize, poses: &HashSet<Vec2>) {
+    if !enabled!(Level::TRACE) {
+        return;
+    }
+    trace!("End of round {}\n{}", round, draw_elves(poses));
+}
+
+fn draw_elves(poses: &HashSet<Vec2>) -> String {
+    let bbox = BBox::from_points(poses.iter().copied()).unwrap();
+
+    let mut field = String::new();
+    for r in bbox.min.y..=bbox.max.y {
+        field += &format!("{:3}  ", r);
+        for c in bbox.min.x..=bbox.max.x {
+            let p = Vec2 { x: c, y: r };
+            field.push(if poses.contains(&p) { '#' } else { '.' });
+        }
+        field.push('\n');
+    }
+    field
+}
+
+fn parse_elves(content: &str) -> Result<HashSet<Vec2>, ParseError> {
+    let rows = parse_lines(content, |line| {
+        let row = line.trim_end();
+        match row.matches(|ch| ch != '#' && ch != '.').next() {
+            Some(tile) => Err(ParseError::at_token(line, tile, "'#' or '.'")),
+            None => Ok(row.to_string()),
+        }
+    })?;
+
+    let mut poses = HashSet::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, ch) in row.chars().enumerate() {
+            if ch == '#' {
+                poses.insert(Vec2 {
+                    x: x as i32,
+                    y: y as i32,
+                });
+            }
+        }
+    }
+
+    if poses.is_empty() {
+        let first = content.lines().next().unwrap_or("");
+        return Err(ParseError::at_end(first, "at least one elf '#'").at_line(1, first));
+    }
+    Ok(poses)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = HashSet<Vec2>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_elves(content)
+    }
+
+    fn part1(elves: &Self::Input) -> impl Display {
+        task_1(elves)
+    }
+
+    fn part2(elves: &Self::Input) -> impl Display {
+        task_2(elves)
+    }
+}
+
+impl Visualize for Day23 {
+    fn simulation(elves: &Self::Input, part: u32) -> Box<dyn Simulation + '_> {
+        let n_rounds = if part == 1 { 10 } else { usize::MAX };
+        Box::new(Grove::new(elves.clone(), n_rounds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+.....
+..##.
+..#..
+.....
+..##.
+.....";
+
+    const EXAMPLE: &str = "\
+....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+    #[test]
+    fn part1_small_example() {
+        let input = Day23::parse(SMALL_EXAMPLE).unwrap();
+        assert_eq!(Day23::part1(&input).to_string(), "25");
+    }
+
+    #[test]
+    fn part2_small_example() {
+        let input = Day23::parse(SMALL_EXAMPLE).unwrap();
+        assert_eq!(Day23::part2(&input).to_string(), "4");
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part1(&input).to_string(), "110");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part2(&input).to_string(), "20");
+    }
+
+    #[test]
+    fn grove_renders_rounds() {
+        let elves = Day23::parse(SMALL_EXAMPLE).unwrap();
+        let mut grove = Grove::new(elves, usize::MAX);
+        assert!(grove.step());
+        assert_eq!(
+            grove.render(),
+            "\
+End of round 1
+  0  ##
+  1  ..
+  2  #.
+  3  .#
+  4  #.
+"
+        );
+        while grove.step() {}
+        assert_eq!(grove.round, 4);
+    }
+
+    #[test]
+    fn rejects_malformed_groves() {
+        let err = Day23::parse("..#\n.o.").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = Day23::parse("...\n...").unwrap_err();
+        assert_eq!(err.expected, "at least one elf '#'");
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day23>(EXAMPLE);
+    }
+}
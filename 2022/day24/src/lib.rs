@@ -0,0 +1,523 @@
+use aoc_core::{ParseError, Simulation, Solution, Visualize};
+#[cfg(any(test, feature = "gen"))]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+use tracing::{debug, enabled, trace, Level};
+
+type Vec2 = geom::Vec2<i32>;
+
+fn task_1(valley: &Valley) -> u32 {
+    let mut expedition = Expedition::new(valley, &[valley.end()]);
+    while expedition.step() {}
+    expedition.t
+}
+
+fn task_2(valley: &Valley) -> u32 {
+    let targets = [valley.end(), valley.start(), valley.end()];
+    let mut expedition = Expedition::new(valley, &targets);
+    while expedition.step() {}
+    expedition.t
+}
+
+/// The expedition crossing the valley to each of its targets in turn, one minute at a time.
+/// It tracks every position the expedition could be at.
+struct Expedition<'a> {
+    valley: &'a Valley,
+    blizzards: Vec<Blizzard>,
+    pos_with_blizzard: HashSet<Vec2>,
+    e_poses: HashSet<Vec2>,
+    targets: VecDeque<Vec2>,
+    t: u32,
+    // Where each position at each minute was reached from, only kept to draw the path
+    parents: Option<HashMap<(Vec2, u32), Vec2>>,
+}
+
+impl<'a> Expedition<'a> {
+    fn new(valley: &'a Valley, targets: &[Vec2]) -> Self {
+        debug!(
+            "Blizzard map h={}, w={} parsed",
+            valley.height, valley.width
+        );
+        Self {
+            valley,
+            blizzards: valley.blizzards.clone(),
+            pos_with_blizzard: valley.blizzards.iter().map(|b| b.pos).collect(),
+            e_poses: HashSet::from([valley.start()]),
+            targets: targets.iter().copied().collect(),
+            t: 0,
+            parents: None,
+        }
+    }
+
+    fn tracking_path(mut self) -> Self {
+        self.parents = Some(HashMap::new());
+        self
+    }
+
+    // The way to the position closest to the current target (or to the last one reached)
+    fn path(&self) -> HashSet<Vec2> {
+        let mut path = HashSet::new();
+        let Some(parents) = &self.parents else {
+            return path;
+        };
+        let target = self.targets.front().copied().unwrap_or(self.valley.end());
+        let Some(mut pos) = self
+            .e_poses
+            .iter()
+            .copied()
+            .min_by_key(|&p| (p.manhattan(target), p.y, p.x))
+        else {
+            return path;
+        };
+
+        let mut t = self.t;
+        path.insert(pos);
+        while let Some(&parent) = parents.get(&(pos, t)) {
+            pos = parent;
+            t -= 1;
+            path.insert(pos);
+        }
+        path
+    }
+
+    fn draw(&self) -> String {
+        let valley = self.valley;
+        let path = self.path();
+        let mut map = String::new();
+        for y in 0..valley.height as i32 {
+            for x in 0..valley.width as i32 {
+                let p = Vec2 { x, y };
+                let is_open = valley.is_on_field(p) || p == valley.start() || p == valley.end();
+                map.push(if !is_open {
+                    '#'
+                } else if self.e_poses.contains(&p) {
+                    'E'
+                } else if path.contains(&p) {
+                    'o'
+                } else if self.pos_with_blizzard.contains(&p) {
+                    'B'
+                } else {
+                    '.'
+                });
+            }
+            map.push('\n');
+        }
+        map
+    }
+}
+
+impl Simulation for Expedition<'_> {
+    fn step(&mut self) -> bool {
+        let Some(&target) = self.targets.front() else {
+            return false;
+        };
+        let valley = self.valley;
+
+        self.t += 1;
+
+        trace!("[T={:3}], states: {}", self.t, self.e_poses.len());
+        if self.e_poses.is_empty() {
+            panic!("No way exists");
+        }
+
+        self.pos_with_blizzard.clear();
+        for blizzard in &mut self.blizzards {
+            blizzard.pos = valley.wrap(blizzard.pos + blizzard.dir);
+            self.pos_with_blizzard.insert(blizzard.pos);
+        }
+
+        let dirs = [
+            Vec2::east(),
+            Vec2::west(),
+            Vec2::south(),
+            Vec2::north(),
+            Vec2::zero(),
+        ];
+
+        let mut e_nxt_poses = HashSet::new();
+        for &e_pos in &self.e_poses {
+            for &dir in &dirs {
+                let nxt_pos = e_pos + dir;
+
+                if let Some(parents) = &mut self.parents {
+                    parents.entry((nxt_pos, self.t)).or_insert(e_pos);
+                }
+
+                if nxt_pos == target {
+                    self.targets.pop_front();
+                    self.e_poses = HashSet::from([target]);
+                    return !self.targets.is_empty();
+                }
+
+                if nxt_pos == valley.start() || nxt_pos == valley.end() {
+                    e_nxt_poses.insert(nxt_pos);
+                    continue;
+                }
+
+                if !valley.is_on_field(nxt_pos) {
+                    continue;
+                }
+
+                if self.pos_with_blizzard.contains(&nxt_pos) {
+                    continue;
+                }
+
+                e_nxt_poses.insert(nxt_pos);
+            }
+        }
+
+        self.e_poses = e_nxt_poses;
+
+        if enabled!(Level::TRACE) {
+            trace!("\n{}", self.draw());
+        }
+        true
+    }
+
+    fn render(&self) -> String {
+        format!("minute {}\n{}", self.t, self.draw())
+    }
+}
+
+#[derive(Debug)]
+pub struct Valley {
+    blizzards: Vec<Blizzard>,
+    height: usize,
+    width: usize,
+}
+
+impl Valley {
+    fn start(&self) -> Vec2 {
+        Vec2 { x: 1, y: 0 }
+    }
+
+    fn end(&self) -> Vec2 {
+        Vec2 {
+            x: (self.width as i32) - 2,
+            y: (self.height as i32) - 1,
+        }
+    }
+
+    fn is_on_field(&self, pos: Vec2) -> bool {
+        pos.x > 0
+            && pos.x < (self.width as i32) - 1
+            && pos.y > 0
+            && pos.y < (self.height as i32) - 1
+    }
+
+    // Brings a blizzard that has left the field back on the opposite side
+    fn wrap(&self, pos: Vec2) -> Vec2 {
+        let width = self.width as i32;
+        let height = self.height as i32;
+        if pos.x == 0 {
+            return Vec2 {
+                x: width - 2,
+                ..pos
+            };
+        }
+        if pos.x == width - 1 {
+            return Vec2 { x: 1, ..pos };
+        }
+        if pos.y == 0 {
+            return Vec2 {
+                y: height - 2,
+                ..pos
+            };
+        }
+        if pos.y == height - 1 {
+            return Vec2 { y: 1, ..pos };
+        }
+        pos
+    }
+}
+
+/// The valley walled in by `#`, entered through the second tile of the top row and left through
+/// the last but one tile of the bottom row.
+fn parse_valley(content: &str) -> Result<Valley, ParseError> {
+    let lines: Vec<&str> = content.trim_end_matches(['\r', '\n']).lines().collect();
+    let height = lines.len();
+    let width = lines.first().map_or(0, |line| line.trim().chars().count());
+    if height < 3 || width < 3 {
+        let last = lines.last().copied().unwrap_or("");
+        let err = ParseError::at_end(last, "valley of at least 3 by 3 tiles");
+        return Err(err.at_line(height.max(1), last));
+    }
+
+    let mut blizzards = Vec::new();
+    for (r, line) in lines.iter().enumerate() {
+        let row = line.trim();
+        if row.chars().count() != width {
+            let err = ParseError::at_token(line, row, format!("row of {} tiles", width));
+            return Err(err.at_line(r + 1, line));
+        }
+        let inner = r > 0 && r < height - 1;
+        let y = r as i32;
+        for (c, (idx, ch)) in row.char_indices().enumerate() {
+            let wall = match r {
+                0 => c != 1,
+                _ if r == height - 1 => c != width - 2,
+                _ => c == 0 || c == width - 1,
+            };
+            let x = c as i32;
+            match ch {
+                '#' if wall => {}
+                '.' if !wall => {}
+                '<' | '>' | 'v' | '^' if !wall && inner => {
+                    blizzards.push(Blizzard::from_char(Vec2 { x, y }, ch))
+                }
+                _ => {
+                    let expected = match (wall, inner) {
+                        (true, _) => "'#'",
+                        (false, false) => "'.'",
+                        (false, true) => "'.', '<', '>', 'v' or '^'",
+                    };
+                    let err = ParseError::at_token(line, &row[idx..idx + ch.len_utf8()], expected);
+                    return Err(err.at_line(r + 1, line));
+                }
+            }
+        }
+    }
+
+    Ok(Valley {
+        blizzards,
+        height,
+        width,
+    })
+}
+
+// Earliest minute to reach `to` when setting off from `from` at minute `t0`, found by a search
+// over positions and minutes modulo the period of the blizzards (so a blocked valley ends it)
+#[cfg(any(test, feature = "gen"))]
+fn crossing(valley: &Valley, from: Vec2, to: Vec2, t0: u32) -> Option<u32> {
+    let w = valley.width as i32 - 2;
+    let h = valley.height as i32 - 2;
+    let period = (w * h) as u32;
+
+    let is_free = |pos: Vec2, t: u32| {
+        if pos == from || pos == to {
+            return true;
+        }
+        if pos.x < 1 || pos.x > w || pos.y < 1 || pos.y > h {
+            return false;
+        }
+        let t = (t % period) as i32;
+        valley.blizzards.iter().all(|b| {
+            let x = 1 + (b.pos.x - 1 + b.dir.x * t).rem_euclid(w);
+            let y = 1 + (b.pos.y - 1 + b.dir.y * t).rem_euclid(h);
+            Vec2 { x, y } != pos
+        })
+    };
+
+    let mut seen = HashSet::from([(from, t0 % period)]);
+    let mut queue = VecDeque::from([(from, t0)]);
+    while let Some((pos, t)) = queue.pop_front() {
+        for dir in [
+            Vec2::zero(),
+            Vec2::east(),
+            Vec2::west(),
+            Vec2::south(),
+            Vec2::north(),
+        ] {
+            let nxt = pos + dir;
+            if !is_free(nxt, t + 1) {
+                continue;
+            }
+            if nxt == to {
+                return Some(t + 1);
+            }
+            if seen.insert((nxt, (t + 1) % period)) {
+                queue.push_back((nxt, t + 1));
+            }
+        }
+    }
+    None
+}
+
+#[derive(Debug, Clone)]
+struct Blizzard {
+    pos: Vec2,
+    dir: Vec2,
+}
+
+impl Blizzard {
+    fn from_char(pos: Vec2, ch: char) -> Self {
+        Self {
+            pos,
+            dir: match ch {
+                '<' => Vec2::west(),
+                '>' => Vec2::east(),
+                'v' => Vec2::south(),
+                '^' => Vec2::north(),
+                _ => panic!("Unexpected ch '{}'", ch),
+            },
+        }
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_valley(content)
+    }
+
+    fn part1(valley: &Self::Input) -> impl Display {
+        task_1(valley)
+    }
+
+    fn part2(valley: &Self::Input) -> impl Display {
+        task_2(valley)
+    }
+}
+
+impl Visualize for Day24 {
+    fn simulation(valley: &Self::Input, part: u32) -> Box<dyn Simulation + '_> {
+        let targets = match part {
+            1 => vec![valley.end()],
+            _ => vec![valley.end(), valley.start(), valley.end()],
+        };
+        Box::new(Expedition::new(valley, &targets).tracking_path())
+    }
+}
+
+/// Generates a random valley of blizzards that can be crossed there and back.
+///
+/// Like in the puzzle, no blizzard moves vertically in the columns of the entrance and exit.
+#[cfg(any(test, feature = "gen"))]
+pub fn generate(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    loop {
+        let width = rng.random_range(4..=12);
+        let height: i32 = rng.random_range(3..=8);
+
+        let mut rows = Vec::new();
+        rows.push(format!("#.{}", "#".repeat(width - 2)));
+        for _ in 1..height - 1 {
+            let mut row = String::from("#");
+            for x in 1..width - 1 {
+                let vertical = x != 1 && x != width - 2;
+                row.push(match rng.random_range(0..10) {
+                    0 => '<',
+                    1 => '>',
+                    2 if vertical => '^',
+                    3 if vertical => 'v',
+                    _ => '.',
+                });
+            }
+            row.push('#');
+            rows.push(row);
+        }
+        rows.push(format!("{}.#", "#".repeat(width - 2)));
+        let text = rows.join("\n");
+
+        let valley = parse_valley(&text).expect("generated valleys are well-formed");
+        let (start, end) = (valley.start(), valley.end());
+        if crossing(&valley, start, end, 0)
+            .and_then(|t| crossing(&valley, end, start, t))
+            .is_some()
+        {
+            return text;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn part1_example() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(Day24::part1(&input).to_string(), "18");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(Day24::part2(&input).to_string(), "54");
+    }
+
+    #[test]
+    fn expedition_renders_minutes() {
+        let valley = Day24::parse(EXAMPLE).unwrap();
+        let mut expedition = Expedition::new(&valley, &[valley.end()]);
+        assert!(expedition.step());
+        assert_eq!(
+            expedition.render(),
+            "\
+minute 1
+#E######
+#EBB.B.#
+#B..BB.#
+#BB.BB.#
+#BB..BB#
+######.#
+"
+        );
+    }
+
+    #[test]
+    fn expedition_draws_its_path() {
+        let valley = Day24::parse(EXAMPLE).unwrap();
+        let mut expedition = Expedition::new(&valley, &[valley.end()]).tracking_path();
+        while expedition.step() {}
+        assert_eq!(expedition.t, 18);
+
+        let frame = expedition.render();
+        let lines: Vec<&str> = frame.lines().collect();
+        assert_eq!(lines[1], "#o######");
+        assert_eq!(lines[6], "######E#");
+        // The shortest way has at least as many cells as the distance to the exit
+        assert!(frame.matches('o').count() >= 10);
+    }
+
+    #[test]
+    fn rejects_malformed_valleys() {
+        let err = Day24::parse("#.###\n#.<x#\n###.#").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = Day24::parse("#.###\n#.<.#\n##..#").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (3, 3, "'#'"));
+        let err = Day24::parse("#.###\n#.<.\n###.#").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "row of 5 tiles"));
+        let err = Day24::parse("#.#\n#.#").unwrap_err();
+        assert_eq!(err.expected, "valley of at least 3 by 3 tiles");
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day24>(EXAMPLE);
+    }
+
+    proptest! {
+        #[test]
+        fn part1_matches_crossing(seed in any::<u64>()) {
+            let valley = Day24::parse(&generate(seed)).unwrap();
+            let (start, end) = (valley.start(), valley.end());
+            prop_assert_eq!(Some(task_1(&valley)), crossing(&valley, start, end, 0));
+        }
+
+        #[test]
+        fn part2_matches_crossing(seed in any::<u64>()) {
+            let valley = Day24::parse(&generate(seed)).unwrap();
+            let (start, end) = (valley.start(), valley.end());
+            let expected = crossing(&valley, start, end, 0)
+                .and_then(|t| crossing(&valley, end, start, t))
+                .and_then(|t| crossing(&valley, start, end, t));
+            prop_assert_eq!(Some(task_2(&valley)), expected);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use std::fmt::Display;
+use tracing::debug;
+
+fn task_1(numbers: &[i64]) -> String {
+    let sum = numbers.iter().sum();
+    debug!("sum: {sum}");
+    to_sfnau(sum)
+}
+
+fn task_2(_numbers: &[i64]) -> i64 {
+    todo!()
+}
+
+fn to_dec(line: &str) -> Result<i64, ParseError> {
+    let mut ans: i64 = 0;
+    const MULT: i64 = 5;
+
+    let number = line.trim();
+    if number.is_empty() {
+        return Err(ParseError::at_end(line, "SNAFU number"));
+    }
+    for (idx, ch) in number.char_indices() {
+        let i = match ch {
+            '=' => -2,
+            '-' => -1,
+            '0' => 0,
+            '1' => 1,
+            '2' => 2,
+            _ => {
+                let digit = &number[idx..idx + ch.len_utf8()];
+                return Err(ParseError::at_token(
+                    line,
+                    digit,
+                    "one of '=', '-', '0', '1', '2'",
+                ));
+            }
+        };
+        ans = ans
+            .checked_mul(MULT)
+            .and_then(|ans| ans.checked_add(i))
+            .ok_or_else(|| ParseError::at_token(line, number, "SNAFU number fitting in 64 bits"))?;
+    }
+
+    Ok(ans)
+}
+
+fn to_sfnau(n: i64) -> String {
+    const MULT: i64 = 5;
+    const OFFSET: i64 = 2;
+
+    if n < 0 {
+        panic!("Negative numbers not supported");
+    }
+    if n == 0 {
+        return "0".into();
+    }
+
+    let mut s: Vec<char> = Vec::new();
+    let mut n = n;
+    while n > 0 {
+        let mut r = n % MULT;
+        if r > (MULT - OFFSET - 1) {
+            r -= MULT;
+        }
+
+        s.push(match r {
+            -2 => '=',
+            -1 => '-',
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            _ => unreachable!(),
+        });
+
+        n -= r;
+        n /= MULT;
+    }
+
+    s.reverse();
+    String::from_iter(s.iter())
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<i64>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(content, to_dec)
+    }
+
+    fn part1(numbers: &Self::Input) -> impl Display {
+        task_1(numbers)
+    }
+
+    fn part2(numbers: &Self::Input) -> impl Display {
+        task_2(numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+    #[test]
+    fn part1_example() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(Day25::part1(&input).to_string(), "2=-1=0");
+    }
+
+    const SNAFU: [(i64, &str); 15] = [
+        (1, "1"),
+        (2, "2"),
+        (3, "1="),
+        (4, "1-"),
+        (5, "10"),
+        (6, "11"),
+        (7, "12"),
+        (8, "2="),
+        (9, "2-"),
+        (10, "20"),
+        (15, "1=0"),
+        (20, "1-0"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+    ];
+
+    #[test]
+    fn to_dec_decodes_snafu() {
+        for (n, snafu) in SNAFU {
+            assert_eq!(to_dec(snafu), Ok(n), "{}", snafu);
+        }
+    }
+
+    #[test]
+    fn to_sfnau_encodes_snafu() {
+        for (n, snafu) in SNAFU {
+            assert_eq!(to_sfnau(n), snafu, "{}", n);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_numbers() {
+        let err = Day25::parse("1=-0\n12311").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = Day25::parse(&"2".repeat(40)).unwrap_err();
+        assert_eq!(err.expected, "SNAFU number fitting in 64 bits");
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day25>(EXAMPLE);
+        // Blank lines would be zeros, which the sum does not show
+        let numbers = Day25::parse(&(EXAMPLE.to_string() + "\n\n")).unwrap();
+        assert_eq!(numbers, Day25::parse(EXAMPLE).unwrap());
+    }
+}
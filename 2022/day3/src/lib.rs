@@ -0,0 +1,268 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use std::error::Error;
+use std::fmt::Display;
+
+/// A set of items, bit `p` standing for the item of priority `p`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Items(u64);
+
+/// Priority of an item: 1 to 26 for `a` to `z`, 27 to 52 for `A` to `Z`.
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => char::from(b'a' + (priority - 1) as u8),
+        _ => char::from(b'A' + (priority - 27) as u8),
+    }
+}
+
+impl Items {
+    pub fn new(items: &str) -> Result<Items, RucksackError> {
+        items.chars()
+            .try_fold(Items(0), |set, ch| match priority(ch) {
+                Some(p) => Ok(Items(set.0 | 1 << p)),
+                None => Err(RucksackError::UnknownItem(ch)),
+            })
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Priorities of the items, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let p = bits.trailing_zeros();
+            bits &= bits - 1;
+            Some(p)
+        })
+    }
+
+    /// The items, by priority.
+    pub fn items(self) -> impl Iterator<Item = char> {
+        self.priorities().map(item)
+    }
+}
+
+/// A rucksack with its two compartments, each holding half of the items.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rucksack {
+    pub left: Items,
+    pub right: Items,
+}
+
+impl Rucksack {
+    pub fn new(items: &str) -> Result<Rucksack, RucksackError> {
+        Items::new(items)?; // an unknown item is the worse problem, and splits no letter
+        if !items.len().is_multiple_of(2) {
+            return Err(RucksackError::OddLength(items.len()));
+        }
+        let (left, right) = items.split_at(items.len() / 2);
+        Ok(Rucksack { left: Items::new(left)?, right: Items::new(right)? })
+    }
+
+    pub fn items(&self) -> Items {
+        self.left.union(self.right)
+    }
+
+    /// Items put into both compartments.
+    pub fn misplaced(&self) -> Items {
+        self.left.intersection(self.right)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RucksackError {
+    /// A rucksack with an odd number of items has no two equal compartments.
+    OddLength(usize),
+    /// An item that is not a letter.
+    UnknownItem(char),
+    /// Rucksacks left over after splitting them into groups of the given size.
+    IncompleteGroup { rucksacks: usize, group_size: usize },
+}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RucksackError::OddLength(n) => write!(f, "odd number of items: {}", n),
+            RucksackError::UnknownItem(ch) => write!(f, "unknown item '{}'", ch),
+            RucksackError::IncompleteGroup { rucksacks, group_size } => write!(
+                f,
+                "{} rucksacks cannot be split into groups of {}",
+                rucksacks, group_size
+            ),
+        }
+    }
+}
+
+impl Error for RucksackError {}
+
+fn parse_rucksack(line: &str) -> Result<Rucksack, ParseError> {
+    let items = line.trim();
+    if items.is_empty() {
+        return Err(ParseError::at_end(line, "rucksack items"));
+    }
+    Rucksack::new(items)
+        .map_err(|err| match err {
+            RucksackError::UnknownItem(_) => {
+                let item = items.matches(|ch| priority(ch).is_none()).next().unwrap();
+                ParseError::at_token(line, item, "item letter")
+            }
+            _ => ParseError::at_token(line, items, "even number of items"),
+        })
+}
+
+/// Items shared by all the rucksacks of each group of `size` consecutive ones.
+pub fn shared_items(rucksacks: &[Rucksack], size: usize) -> Result<Vec<Items>, RucksackError> {
+    if size == 0 || !rucksacks.len().is_multiple_of(size) {
+        return Err(RucksackError::IncompleteGroup { rucksacks: rucksacks.len(), group_size: size });
+    }
+    Ok(rucksacks.chunks(size)
+        .map(|group| {
+            group.iter()
+                .map(Rucksack::items)
+                .fold(Items(u64::MAX), Items::intersection)
+        })
+        .collect())
+}
+
+fn task_1(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks.iter()
+        .flat_map(|r| r.misplaced().priorities())
+        .sum()
+}
+
+/// Number of elves in a group, their badge is the item all of them carry.
+const GROUP_SIZE: usize = 3;
+
+// Only part 2 needs whole groups, so an incomplete last one is its error and not a parse one
+fn task_2(rucksacks: &[Rucksack]) -> Result<u32, RucksackError> {
+    Ok(shared_items(rucksacks, GROUP_SIZE)?
+        .into_iter()
+        .flat_map(Items::priorities)
+        .sum())
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(content, parse_rucksack)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> impl Display {
+        task_1(rucksacks)
+    }
+
+    fn part2(rucksacks: &Self::Input) -> impl Display {
+        // The runner reports a panicking part as failed with this message
+        task_2(rucksacks).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&input).to_string(), "157");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part2(&input).to_string(), "70");
+    }
+
+    #[test]
+    fn items_by_priority() {
+        let items = Items::new("zaZAa").unwrap();
+        assert_eq!(items.len(), 4);
+        assert_eq!(items.priorities().collect::<Vec<_>>(), [1, 26, 27, 52]);
+        assert_eq!(items.items().collect::<String>(), "azAZ");
+        assert_eq!(Items::new("ab1"), Err(RucksackError::UnknownItem('1')));
+    }
+
+    #[test]
+    fn shares_items_in_groups_of_any_size() {
+        let rucksacks = Day3::parse(EXAMPLE).unwrap();
+        let badges = |size| -> Vec<String> {
+            shared_items(&rucksacks, size).unwrap()
+                .into_iter()
+                .map(|items| items.items().collect())
+                .collect()
+        };
+        assert_eq!(badges(3), ["r", "Z"]);
+        assert_eq!(badges(2), ["frsFM", "qvwBT", "GJZ"]);
+        assert_eq!(badges(6), [""]);
+        assert_eq!(
+            shared_items(&rucksacks, 4),
+            Err(RucksackError::IncompleteGroup { rucksacks: 6, group_size: 4 })
+        );
+    }
+
+    #[test]
+    fn rejects_odd_rucksacks() {
+        assert_eq!(Rucksack::new("abc"), Err(RucksackError::OddLength(3)));
+        let err = Day3::parse("abab\nabc").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "even number of items"));
+        let err = Day3::parse("ab-b").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        // Letters of several bytes are unknown items rather than split in two
+        assert_eq!(Rucksack::new("aéb"), Err(RucksackError::UnknownItem('é')));
+        let err = Day3::parse("aébc").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (1, 2, "item letter"));
+    }
+
+    #[test]
+    fn rejects_incomplete_groups_in_part2_only() {
+        let rucksacks = Day3::parse("abab\nacac\n").unwrap();
+        assert_eq!(Day3::part1(&rucksacks).to_string(), "7");
+        assert_eq!(task_2(&rucksacks), Err(RucksackError::IncompleteGroup { rucksacks: 2, group_size: 3 }));
+        let rucksacks = Day3::parse(&format!("{}\nabab\r\n\r\n", EXAMPLE)).unwrap();
+        assert_eq!(Day3::part1(&rucksacks).to_string(), "160");
+        assert_eq!(
+            task_2(&rucksacks).unwrap_err().to_string(),
+            "7 rucksacks cannot be split into groups of 3"
+        );
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day3>(EXAMPLE);
+    }
+}
@@ -0,0 +1,115 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use geom::{Interval, IntervalSet};
+use std::fmt::Display;
+
+/// The sections assigned to an elf.
+pub type Assignment = Interval<i32>;
+
+fn parse_assignment(line: &str, assignment: &str) -> Result<Assignment, ParseError> {
+    let (lhs, rhs) = assignment.split_once('-')
+        .ok_or_else(|| ParseError::at_token(line, assignment, "'<id>-<id>'"))?;
+    let parse_id = |id: &str| id.parse::<i32>()
+        .map_err(|_| ParseError::at_token(line, id, "section id"));
+    let lhs_id = parse_id(lhs)?;
+    let rhs_id = parse_id(rhs)?;
+    Assignment::new(lhs_id, rhs_id)
+        .ok_or_else(|| ParseError::at_token(line, assignment, "ids in increasing order"))
+}
+
+fn parse_assignments(line: &str) -> Result<(Assignment, Assignment), ParseError> {
+    let assignment_pair = line.trim();
+    let (a1, a2) = assignment_pair.split_once(',')
+        .ok_or_else(|| ParseError::at_token(line, assignment_pair, "two assignments separated by ','"))?;
+    let a1 = parse_assignment(line, a1)?;
+    let a2 = parse_assignment(line, a2)?;
+    Ok((a1, a2))
+}
+
+fn task_1(pairs: &[(Assignment, Assignment)]) -> usize {
+    pairs.iter()
+        .filter(|(a1, a2)| a1.contains_interval(*a2) || a2.contains_interval(*a1))
+        .count()
+}
+
+fn task_2(pairs: &[(Assignment, Assignment)]) -> usize {
+    pairs.iter()
+        .filter(|(a1, a2)| a1.overlaps(*a2))
+        .count()
+}
+
+/// Sections assigned to at least one elf; its `len()` is the total coverage.
+pub fn coverage(pairs: &[(Assignment, Assignment)]) -> IntervalSet<i32> {
+    pairs.iter()
+        .flat_map(|&(a1, a2)| [a1, a2])
+        .collect()
+}
+
+/// Indices of the pairs whose elves are both assigned `section`.
+pub fn overlapping_at(pairs: &[(Assignment, Assignment)], section: i32) -> Vec<usize> {
+    pairs.iter()
+        .enumerate()
+        .filter(|(_, (a1, a2))| a1.intersection(*a2).is_some_and(|common| common.contains(section)))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Assignment, Assignment)>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(content, parse_assignments)
+    }
+
+    fn part1(pairs: &Self::Input) -> impl Display {
+        task_1(pairs)
+    }
+
+    fn part2(pairs: &Self::Input) -> impl Display {
+        task_2(pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input).to_string(), "2");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input).to_string(), "4");
+    }
+
+    #[test]
+    fn coverage_of_example() {
+        let pairs = Day4::parse(EXAMPLE).unwrap();
+        let sections = coverage(&pairs);
+        assert_eq!(sections.intervals(), [Interval::new(2, 9).unwrap()]);
+        assert_eq!(sections.len(), Some(8));
+        assert_eq!(coverage(&pairs[..1]).gaps().collect::<Vec<_>>(), [Interval::point(5)]);
+
+        assert_eq!(overlapping_at(&pairs, 6), [3, 4, 5]);
+        assert_eq!(overlapping_at(&pairs, 7), [2, 3]);
+        assert_eq!(overlapping_at(&pairs, 1), []);
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day4>(EXAMPLE);
+    }
+}
@@ -0,0 +1,344 @@
+use aoc_core::{ParseError, Solution, Tokens};
+#[cfg(any(test, feature = "gen"))]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{collections::VecDeque, fmt::Display};
+
+#[derive(Debug)]
+pub struct Move {
+    amount: usize,
+    src: usize,
+    dst: usize,
+}
+
+// `heights` are the numbers of crates on the stacks before the move, updated after it
+fn parse_move(line: &str, heights: &mut [usize]) -> Result<Move, ParseError> {
+    let mut tokens = Tokens::new(line);
+    tokens.literal("move")?;
+    let amount_word = tokens.word("number of crates")?;
+    let amount: usize = amount_word
+        .parse()
+        .map_err(|_| tokens.error(amount_word, "number of crates"))?;
+    tokens.literal("from")?;
+    let src = parse_stack(&mut tokens, heights.len())?;
+    tokens.literal("to")?;
+    let dst = parse_stack(&mut tokens, heights.len())?;
+    tokens.finish()?;
+
+    if amount > heights[src] {
+        let expected = format!("at most {} crates of stack {}", heights[src], src + 1);
+        return Err(ParseError::at_token(line, amount_word, expected));
+    }
+    heights[src] -= amount;
+    heights[dst] += amount;
+    Ok(Move { amount, src, dst })
+}
+
+fn parse_stack(tokens: &mut Tokens, n_stacks: usize) -> Result<usize, ParseError> {
+    let expected = format!("stack from 1 to {}", n_stacks);
+    tokens.parse_with(&expected, |word| {
+        word.parse::<usize>()
+            .ok()?
+            .checked_sub(1)
+            .filter(|&stack| stack < n_stacks)
+    })
+}
+
+// The labels under the drawing, ` 1   2   3`, give the number of stacks
+fn parse_labels(line: &str) -> Result<usize, ParseError> {
+    let mut tokens = Tokens::new(line);
+    let mut n_stacks = 0;
+    loop {
+        let Some(word) = tokens.rest().next() else {
+            break;
+        };
+        if word.parse::<usize>() != Ok(n_stacks + 1) {
+            return Err(tokens.error(word, format!("stack label {}", n_stacks + 1)));
+        }
+        n_stacks += 1;
+    }
+    if n_stacks == 0 {
+        return Err(ParseError::at_end(line, "stack label 1"));
+    }
+    Ok(n_stacks)
+}
+
+// A row of the drawing, `[A] [B]     [D]`: the crates on it with their stacks
+fn parse_row(line: &str, n_stacks: usize) -> Result<Vec<(usize, &str)>, ParseError> {
+    let expected = "crate like '[A]' or blank";
+    if let Some((idx, ch)) = line.char_indices().find(|(_, ch)| !ch.is_ascii()) {
+        return Err(ParseError::at_token(
+            line,
+            &line[idx..idx + ch.len_utf8()],
+            expected,
+        ));
+    }
+
+    let line = line.trim_end();
+    let mut crates = Vec::new();
+    for (stack, start) in (0..line.len()).step_by(4).enumerate() {
+        let cell = &line[start..line.len().min(start + 3)];
+        if let Some(separator) = line.get(start + 3..start + 4).filter(|&sep| sep != " ") {
+            return Err(ParseError::at_token(line, separator, "' ' between stacks"));
+        }
+        let bytes = cell.as_bytes();
+        match bytes {
+            b"   " => continue,
+            [b'[', name, b']'] if name.is_ascii_uppercase() => {}
+            _ => return Err(ParseError::at_token(line, cell, expected)),
+        }
+        if stack >= n_stacks {
+            let expected = format!("crate on one of the {} stacks", n_stacks);
+            return Err(ParseError::at_token(line, cell, expected));
+        }
+        crates.push((stack, cell));
+    }
+    Ok(crates)
+}
+
+// The drawing of the stacks and the labels under it, a blank line, then the moves
+fn parse(content: &str) -> Result<(Vec<VecDeque<char>>, Vec<Move>), ParseError> {
+    let lines: Vec<&str> = content.lines().collect();
+    let Some(blank) = lines.iter().position(|line| line.trim().is_empty()) else {
+        let last = lines.last().copied().unwrap_or("");
+        let err = ParseError::at_end(last, "blank line before the moves");
+        return Err(err.at_line(lines.len().max(1), last));
+    };
+    if blank == 0 {
+        return Err(ParseError::at_end("", "drawing of the stacks").at_line(1, lines[0]));
+    }
+
+    let labels = lines[blank - 1];
+    let n_stacks = parse_labels(labels).map_err(|err| err.at_line(blank, labels))?;
+    let mut crates: Vec<VecDeque<char>> = vec![VecDeque::new(); n_stacks];
+    // Bottom row first, so that a crate has to rest on another one or on the floor
+    for (idx, &line) in lines[..blank - 1].iter().enumerate().rev() {
+        let level = blank - 2 - idx;
+        for (stack, cell) in parse_row(line, n_stacks).map_err(|err| err.at_line(idx + 1, line))? {
+            if crates[stack].len() != level {
+                let err = ParseError::at_token(line, cell, "crate resting on another one");
+                return Err(err.at_line(idx + 1, line));
+            }
+            crates[stack].push_back(char::from(cell.as_bytes()[1]));
+        }
+    }
+
+    let mut heights: Vec<usize> = crates.iter().map(VecDeque::len).collect();
+    let mut moves = Vec::new();
+    for (idx, &line) in lines.iter().enumerate().skip(blank + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        moves.push(parse_move(line, &mut heights).map_err(|err| err.at_line(idx + 1, line))?);
+    }
+    Ok((crates, moves))
+}
+
+fn task_1(crates: &[VecDeque<char>], moves: &[Move]) -> String {
+    let mut crates = crates.to_vec();
+
+    for mv in moves {
+        for _ in 0..mv.amount {
+            let ch = crates[mv.src].pop_back().unwrap();
+            crates[mv.dst].push_back(ch);
+        }
+    }
+
+    crates.iter().filter_map(|c| c.back()).collect::<String>()
+}
+
+fn task_2(crates: &[VecDeque<char>], moves: &[Move]) -> String {
+    let mut crates = crates.to_vec();
+
+    let mut interm_crate = Vec::new();
+    for mv in moves {
+        for _ in 0..mv.amount {
+            let ch = crates[mv.src].pop_back().unwrap();
+            interm_crate.push(ch);
+        }
+        while let Some(element) = interm_crate.pop() {
+            crates[mv.dst].push_back(element);
+        }
+    }
+
+    crates.iter().filter_map(|c| c.back()).collect::<String>()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<VecDeque<char>>, Vec<Move>);
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse(content)
+    }
+
+    fn part1((crates, moves): &Self::Input) -> impl Display {
+        task_1(crates, moves)
+    }
+
+    fn part2((crates, moves): &Self::Input) -> impl Display {
+        task_2(crates, moves)
+    }
+}
+
+/// Generates a random drawing of crate stacks followed by moves that never empty a stack.
+#[cfg(any(test, feature = "gen"))]
+pub fn generate(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let n_stacks = rng.random_range(1..=9);
+    let mut stacks: Vec<usize> = (0..n_stacks).map(|_| rng.random_range(1..=8)).collect();
+    let crates: Vec<Vec<char>> = stacks
+        .iter()
+        .map(|&len| (0..len).map(|_| rng.random_range('A'..='Z')).collect())
+        .collect();
+
+    let mut moves = Vec::new();
+    for _ in 0..rng.random_range(0..=30) {
+        let sources: Vec<usize> = (0..n_stacks).filter(|&i| stacks[i] > 1).collect();
+        if n_stacks < 2 || sources.is_empty() {
+            break;
+        }
+        let src = sources[rng.random_range(0..sources.len())];
+        let dst = (src + rng.random_range(1..n_stacks)) % n_stacks;
+        let amount = rng.random_range(1..stacks[src]);
+        stacks[src] -= amount;
+        stacks[dst] += amount;
+        moves.push(format!("move {} from {} to {}", amount, src + 1, dst + 1));
+    }
+
+    let height = crates.iter().map(Vec::len).max().unwrap();
+    let mut drawing: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            let row: Vec<String> = crates
+                .iter()
+                .map(|c| match c.get(level) {
+                    Some(ch) => format!("[{}]", ch),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            row.join(" ").trim_end().to_string()
+        })
+        .collect();
+    let labels: Vec<String> = (1..=n_stacks).map(|i| format!(" {} ", i)).collect();
+    drawing.push(labels.join(" "));
+
+    format!("{}\n\n{}", drawing.join("\n"), moves.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Moves whole slices at once instead of crate by crate
+    fn naive(crates: &[VecDeque<char>], moves: &[Move], reverse: bool) -> String {
+        let mut stacks: Vec<Vec<char>> =
+            crates.iter().map(|c| c.iter().copied().collect()).collect();
+        for mv in moves {
+            let at = stacks[mv.src].len() - mv.amount;
+            let mut moved = stacks[mv.src].split_off(at);
+            if reverse {
+                moved.reverse();
+            }
+            stacks[mv.dst].extend(moved);
+        }
+        stacks.iter().map(|s| s.last().unwrap()).collect()
+    }
+
+    const EXAMPLE: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input).to_string(), "CMZ");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input).to_string(), "MCD");
+    }
+
+    #[test]
+    fn rejects_malformed_drawings() {
+        let err = Day5::parse("move 1 from 1 to 2").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (1, "blank line before the moves")
+        );
+        let err = Day5::parse("hello\nworld\n\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 1, "stack label 1")
+        );
+        let err = Day5::parse(&EXAMPLE.replace(" 2 ", " 3 ")).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (4, 6, "stack label 2")
+        );
+        let err = Day5::parse(&EXAMPLE.replace("[C]", "[c]")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        let err = Day5::parse(&EXAMPLE.replace("[N] [C]", "[N]-[C]")).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 4, "' ' between stacks")
+        );
+        let err = Day5::parse(&EXAMPLE.replace("[M] [P]", "[M] [P] [Q]")).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 13));
+        assert_eq!(err.expected, "crate on one of the 3 stacks");
+        let err = Day5::parse(&EXAMPLE.replace("[Z] [M]", "[Z]    ")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.expected, "crate resting on another one");
+        let err = Day5::parse(&EXAMPLE.replace("[Z]", "[É]")).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 2));
+    }
+
+    #[test]
+    fn rejects_impossible_moves() {
+        let err = Day5::parse(&EXAMPLE.replace("from 2 to 1", "from 4 to 1")).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (6, 13, "stack from 1 to 3")
+        );
+        let err = Day5::parse(&EXAMPLE.replace("to 3", "to 0")).unwrap_err();
+        assert_eq!((err.line, err.column), (7, 18));
+        // Stack 1 holds 3 crates after the first move
+        let err = Day5::parse(&EXAMPLE.replace("move 3", "move 4")).unwrap_err();
+        assert_eq!((err.line, err.column), (7, 6));
+        assert_eq!(err.expected, "at most 3 crates of stack 1");
+        let err = Day5::parse(&format!("{}\nmove one from 1 to 2", EXAMPLE)).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (10, 6, "number of crates")
+        );
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day5>(EXAMPLE);
+    }
+
+    proptest! {
+        #[test]
+        fn part1_matches_naive(seed in any::<u64>()) {
+            let (crates, moves) = Day5::parse(&generate(seed)).unwrap();
+            prop_assert_eq!(task_1(&crates, &moves), naive(&crates, &moves, true));
+        }
+
+        #[test]
+        fn part2_matches_naive(seed in any::<u64>()) {
+            let (crates, moves) = Day5::parse(&generate(seed)).unwrap();
+            prop_assert_eq!(task_2(&crates, &moves), naive(&crates, &moves, false));
+        }
+    }
+}
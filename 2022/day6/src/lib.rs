@@ -0,0 +1,125 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use itertools::Itertools;
+use std::fmt::Display;
+
+const PACKET_MARKER_LEN: usize = 4;
+const MESSAGE_MARKER_LEN: usize = 14;
+
+// Number of characters read once the last `marker_len` ones are all different
+fn find_marker(stream: &str, marker_len: usize) -> Option<usize> {
+    stream
+        .as_bytes()
+        .windows(marker_len)
+        .position(|window| window.iter().unique().count() == marker_len)
+        .map(|i| i + marker_len)
+}
+
+// A single line of letters, holding both markers
+fn parse_stream(content: &str) -> Result<String, ParseError> {
+    let streams = parse_lines(content, |line| {
+        match line.matches(|ch: char| !ch.is_ascii_lowercase()).next() {
+            Some(ch) => Err(ParseError::at_token(line, ch, "letter from 'a' to 'z'")),
+            None => Ok(line.to_string()),
+        }
+    })?;
+    let stream = match streams.as_slice() {
+        [] => return Err(ParseError::at_end("", "datastream").at_line(1, "")),
+        [stream] => stream,
+        [_, next, ..] => {
+            let err = ParseError::new(1, 1, "end of the datastream", "another line");
+            return Err(err.at_line(2, next));
+        }
+    };
+
+    let markers = [
+        (PACKET_MARKER_LEN, "start-of-packet"),
+        (MESSAGE_MARKER_LEN, "start-of-message"),
+    ];
+    for (marker_len, name) in markers {
+        if find_marker(stream, marker_len).is_none() {
+            let expected = format!("{} marker of {} different letters", name, marker_len);
+            return Err(ParseError::at_end(stream, expected).at_line(1, stream));
+        }
+    }
+    Ok(stream.clone())
+}
+
+fn solve(stream: &str, marker_len: usize) -> usize {
+    find_marker(stream, marker_len).expect("the markers are checked when parsing")
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_stream(content)
+    }
+
+    fn part1(stream: &Self::Input) -> impl Display {
+        solve(stream, PACKET_MARKER_LEN)
+    }
+
+    fn part2(stream: &Self::Input) -> impl Display {
+        solve(stream, MESSAGE_MARKER_LEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn part1_examples() {
+        for (stream, expected, _) in EXAMPLES {
+            let input = Day6::parse(stream).unwrap();
+            assert_eq!(Day6::part1(&input).to_string(), expected.to_string());
+        }
+    }
+
+    #[test]
+    fn part2_examples() {
+        for (stream, _, expected) in EXAMPLES {
+            let input = Day6::parse(stream).unwrap();
+            assert_eq!(Day6::part2(&input).to_string(), expected.to_string());
+        }
+    }
+
+    #[test]
+    fn rejects_streams_without_markers() {
+        let err = Day6::parse("abc").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(
+            err.expected,
+            "start-of-packet marker of 4 different letters"
+        );
+        let err = Day6::parse("abcdabcdabcd").unwrap_err();
+        assert_eq!(
+            err.expected,
+            "start-of-message marker of 14 different letters"
+        );
+        let err = Day6::parse("").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (1, "datastream"));
+        let err = Day6::parse("mjqjpqmgb ljsphdztnvjfqwrcgsmlb").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 10));
+        let err = Day6::parse(&format!("{}\n\n{}", EXAMPLES[0].0, EXAMPLES[1].0)).unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (2, "end of the datastream")
+        );
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day6>(EXAMPLES[0].0);
+    }
+}
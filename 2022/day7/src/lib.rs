@@ -0,0 +1,245 @@
+use aoc_core::{ParseError, Solution, Tokens};
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+enum Command<'a> {
+    Ls,
+    Cd(&'a str),
+}
+
+fn parse_command(line: &str) -> Result<Command<'_>, ParseError> {
+    let mut tokens = Tokens::new(line);
+    tokens.literal("$")?;
+
+    let command = match tokens.word("command")? {
+        "cd" => Command::Cd(tokens.word("directory name")?),
+        "ls" => Command::Ls,
+        other => return Err(tokens.error(other, "'cd' or 'ls'")),
+    };
+    tokens.finish()?;
+    Ok(command)
+}
+
+enum FsObj {
+    File(String, u32),
+    Dir(String),
+}
+
+fn parse_fs_obj(line: &str) -> Result<FsObj, ParseError> {
+    let mut tokens = Tokens::new(line);
+    let first = tokens.word("'dir' or file size")?;
+    let fs_obj = if first == "dir" {
+        FsObj::Dir(tokens.word("directory name")?.to_string())
+    } else {
+        let size = first
+            .parse::<u32>()
+            .map_err(|_| tokens.error(first, "'dir' or file size"))?;
+        FsObj::File(tokens.word("file name")?.to_string(), size)
+    };
+    tokens.finish()?;
+    Ok(fs_obj)
+}
+
+pub struct Node {
+    fs_obj: FsObj,
+    children: Vec<Rc<RefCell<Node>>>,
+    parent: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node {
+    fn new_dir(name: &str, parent: Option<Rc<RefCell<Node>>>) -> Rc<RefCell<Node>> {
+        let node = Node {
+            fs_obj: FsObj::Dir(name.to_string()),
+            children: Vec::new(),
+            parent,
+        };
+        Rc::new(RefCell::new(node))
+    }
+
+    fn new_file(name: &str, size: u32, parent: Option<Rc<RefCell<Node>>>) -> Rc<RefCell<Node>> {
+        let node = Node {
+            fs_obj: FsObj::File(name.to_string(), size),
+            children: Vec::new(),
+            parent,
+        };
+        Rc::new(RefCell::new(node))
+    }
+
+    fn add_child(&mut self, node: Rc<RefCell<Node>>) {
+        self.children.push(node);
+    }
+
+    fn task_1(&self) -> (u32 /* subtree size */, u32 /* task1 answer*/) {
+        if let FsObj::File(_, size) = self.fs_obj {
+            return (size, 0);
+        }
+
+        let mut size: u32 = 0;
+        let mut answer: u32 = 0;
+        for child in &self.children {
+            let (s, a) = child.borrow_mut().task_1();
+            size += s;
+            answer += a;
+        }
+
+        if size <= 100000 {
+            answer += size;
+        }
+
+        (size, answer)
+    }
+
+    fn total_size(&self) -> u32 {
+        let (size, _) = self.task_1();
+        size
+    }
+
+    fn task_2_impl(&self, required_to_free: u32) -> (u32 /* size */, u32 /* answer */) {
+        if let FsObj::File(_, size) = self.fs_obj {
+            return (size, 0);
+        }
+
+        let mut size: u32 = 0;
+        let mut answer: u32 = 0;
+        for child in &self.children {
+            let (s, a) = child.borrow_mut().task_2_impl(required_to_free);
+            size += s;
+
+            if a >= required_to_free && (answer == 0 || a < answer) {
+                answer = a;
+            }
+        }
+
+        if size >= required_to_free && (answer == 0 || size < answer) {
+            answer = size;
+        }
+
+        (size, answer)
+    }
+
+    fn task_2(&self, required_to_free: u32) -> u32 {
+        let (_, a) = self.task_2_impl(required_to_free);
+        a
+    }
+}
+
+fn build_tree(content: &str) -> Result<Rc<RefCell<Node>>, ParseError> {
+    let root = Node::new_dir("/", None);
+    let mut cur = root.clone();
+
+    for (idx, line) in content.trim_end_matches(['\r', '\n']).lines().enumerate() {
+        let at_line = |err: ParseError| err.at_line(idx + 1, line);
+        let line = line.trim();
+        if line.starts_with('$') {
+            let command = parse_command(line).map_err(at_line)?;
+            if let Command::Cd(dir) = command {
+                match dir {
+                    "/" => cur = root.clone(),
+                    ".." => {
+                        let cur_clone = cur;
+                        cur = cur_clone.borrow_mut().parent.as_ref().unwrap().clone();
+                    }
+                    _ => {
+                        let mut found = false;
+                        let cur_clone = cur.clone();
+                        for child in &cur_clone.borrow_mut().children {
+                            if let FsObj::Dir(name) = &child.borrow().fs_obj {
+                                if name == dir {
+                                    cur = child.clone();
+                                    found = true;
+                                    break;
+                                }
+                            }
+                        }
+                        if !found {
+                            let child = Node::new_dir(dir, Some(cur.clone()));
+                            cur.borrow_mut().add_child(child.clone());
+                            cur = child;
+                        }
+                    }
+                }
+            }
+            continue;
+        }
+
+        match parse_fs_obj(line).map_err(at_line)? {
+            FsObj::File(name, size) => {
+                let child = Node::new_file(&name, size, Some(cur.clone()));
+                cur.borrow_mut().add_child(child);
+            }
+            FsObj::Dir(name) => {
+                let child = Node::new_dir(&name, Some(cur.clone()));
+                cur.borrow_mut().add_child(child);
+            }
+        }
+    }
+    Ok(root)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Rc<RefCell<Node>>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        build_tree(content)
+    }
+
+    fn part1(root: &Self::Input) -> impl Display {
+        let (_, answer) = root.borrow().task_1();
+        answer
+    }
+
+    fn part2(root: &Self::Input) -> impl Display {
+        let used_space = root.borrow().total_size();
+        let unused_space = 70000000 - used_space;
+        let required_to_free = 30000000 - unused_space;
+        root.borrow().task_2(required_to_free)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input).to_string(), "95437");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input).to_string(), "24933642");
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day7>(EXAMPLE);
+    }
+}
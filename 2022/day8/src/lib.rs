@@ -0,0 +1,135 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use grid::{Grid, Pos};
+use std::fmt::Display;
+
+fn task_1(grid: &Grid<char>) -> u32 {
+    let mut count_visible = 0;
+
+    for (p, &ch) in grid.iter() {
+        let visible = Pos::dirs4()
+            .into_iter()
+            .any(|dir| grid.ray(p, dir).all(|(_, &other)| other < ch));
+
+        if visible {
+            count_visible += 1;
+        }
+    }
+
+    count_visible
+}
+
+fn task_2(grid: &Grid<char>) -> usize {
+    let mut max_scenic_score = 0;
+
+    for (p, &ch) in grid.iter() {
+        let scenic_score: usize = Pos::dirs4()
+            .into_iter()
+            .map(|dir| viewing_distance(grid, p, dir, ch))
+            .product();
+
+        if scenic_score > max_scenic_score {
+            max_scenic_score = scenic_score;
+        }
+    }
+
+    max_scenic_score
+}
+
+fn viewing_distance(grid: &Grid<char>, p: Pos, dir: Pos, height: char) -> usize {
+    let mut distance = 0;
+    for (_, &other) in grid.ray(p, dir) {
+        distance += 1;
+        if other >= height {
+            break;
+        }
+    }
+    distance
+}
+
+// Rows of digits, all of the same length
+fn parse_grid(content: &str) -> Result<Grid<char>, ParseError> {
+    let mut width = None;
+    let rows = parse_lines(content, |line| {
+        if let Some(ch) = line.matches(|ch: char| !ch.is_ascii_digit()).next() {
+            return Err(ParseError::at_token(
+                line,
+                ch,
+                "tree height from '0' to '9'",
+            ));
+        }
+        let n = line.chars().count();
+        match width.get_or_insert(n) {
+            w if *w == n => Ok(()),
+            w => Err(ParseError::at_token(
+                line,
+                line,
+                format!("row of {} trees", w),
+            )),
+        }
+    })?;
+    if rows.is_empty() {
+        return Err(ParseError::at_end("", "row of trees").at_line(1, ""));
+    }
+    Ok(Grid::parse(content, |ch| ch))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<char>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(content)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        task_1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> impl Display {
+        task_2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn part1_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&input).to_string(), "21");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part2(&input).to_string(), "8");
+    }
+
+    #[test]
+    fn rejects_malformed_grids() {
+        let err = Day8::parse("303\n2a5").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected, "tree height from '0' to '9'");
+        let err = Day8::parse("303\n25\n653").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 1, "row of 3 trees")
+        );
+        let err = Day8::parse("\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (1, "row of trees"));
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day8>(EXAMPLE);
+    }
+}
@@ -0,0 +1,522 @@
+use aoc_core::{parse_lines, ParseError, Simulation, Solution, Tokens, Visualize};
+use geom::Vec2;
+#[cfg(any(test, feature = "gen"))]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Display},
+    mem,
+};
+use tracing::trace;
+
+fn task_1(moves: &[Move]) -> usize {
+    let mut visited = HashSet::new();
+
+    let mut h_pos = Point::new(0, 0);
+    let mut t_pos = h_pos;
+    let mut layout = Layout::Same;
+
+    visited.insert(t_pos);
+
+    for mv in moves {
+        for _ in 0..mv.n {
+            h_pos += mv.dir;
+            match layout {
+                Layout::Same => {
+                    layout = Layout::Queue(mv.dir);
+                }
+                Layout::Queue(qdir) => {
+                    if mv.dir == qdir {
+                        t_pos += mv.dir;
+                    } else if mv.dir == qdir.rot180() {
+                        layout = Layout::Same;
+                    } else if mv.dir == qdir.rot_ccw() {
+                        layout = Layout::Diag(qdir.rot_ccw());
+                    } else {
+                        layout = Layout::Diag(qdir);
+                    }
+                }
+                Layout::Diag(qdir) => {
+                    if mv.dir == qdir {
+                        t_pos += mv.dir + mv.dir.rot_cw();
+                        layout = Layout::Queue(qdir);
+                    } else if mv.dir == qdir.rot_ccw() {
+                        layout = Layout::Queue(qdir);
+                    } else if mv.dir == qdir.rot180() {
+                        layout = Layout::Queue(qdir.rot_cw())
+                    } else {
+                        t_pos += mv.dir + mv.dir.rot_ccw();
+                        layout = Layout::Queue(mv.dir);
+                    }
+                }
+            }
+            visited.insert(t_pos);
+        }
+    }
+
+    visited.len()
+}
+
+struct Knot {
+    pos: Point,
+    layout: Layout,
+}
+
+impl Debug for Knot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(format!("({:?})", self.layout).as_str())
+    }
+}
+
+#[derive(Default)]
+struct Rope {
+    head: Point,
+    tail: Vec<Knot>,
+}
+
+impl Rope {
+    fn new(tail_len: usize) -> Rope {
+        let mut rope = Rope {
+            head: Point::zero(),
+            tail: Vec::new(),
+        };
+        for _ in 0..tail_len {
+            rope.tail.push(Knot {
+                pos: Point::zero(),
+                layout: Layout::Same,
+            });
+        }
+        rope
+    }
+
+    fn last_knot(&self) -> Point {
+        self.tail.last().unwrap().pos
+    }
+
+    // Draws an `h` x `w` window around `center`, `visited` positions are marked with '#'
+    fn draw(&self, center: Point, h: i32, w: i32, visited: &HashSet<Point>) -> String {
+        let mut hm = HashMap::new();
+        hm.insert(self.head, 'H');
+        for (idx, knot) in self.tail.iter().enumerate() {
+            if hm.contains_key(&knot.pos) {
+                continue;
+            }
+            hm.insert(knot.pos, (idx + 1).to_string().chars().next().unwrap());
+        }
+
+        let mut field = String::new();
+        for r in 0..h {
+            for c in 0..w {
+                let p = Point::new(center.x - w / 2 + c, center.y - h / 2 + r);
+                field.push(match hm.get(&p) {
+                    Some(&ch) => ch,
+                    None if visited.contains(&p) => '#',
+                    None => '.',
+                });
+            }
+            field.push('\n');
+        }
+        field
+    }
+
+    fn _on_front_knot_moved(mut self, front_knot_step: Point, knot_idx: usize) -> Rope {
+        if knot_idx == self.tail.len() {
+            return self;
+        }
+        let knot = &mut self.tail[knot_idx];
+        match knot.layout {
+            Layout::Same => {
+                if front_knot_step.x == 0 || front_knot_step.y == 0 {
+                    knot.layout = Layout::Queue(front_knot_step);
+                } else {
+                    let x = front_knot_step.x;
+                    let y = front_knot_step.y;
+                    knot.layout = Layout::Diag(Point::new((x + y) / 2, (-x + y) / 2));
+                }
+            }
+            Layout::Queue(qdir) => {
+                if front_knot_step == qdir.front() {
+                    knot.pos += front_knot_step;
+                    return self._on_front_knot_moved(front_knot_step, knot_idx + 1);
+                } else if front_knot_step == qdir.back() {
+                    knot.layout = Layout::Same;
+                } else if front_knot_step == qdir.left() {
+                    knot.layout = Layout::Diag(qdir.left());
+                } else if front_knot_step == qdir.right() {
+                    knot.layout = Layout::Diag(qdir.front());
+                } else if front_knot_step == qdir.front_right()
+                    || front_knot_step == qdir.front_left()
+                {
+                    knot.pos += front_knot_step;
+                    return self._on_front_knot_moved(front_knot_step, knot_idx + 1);
+                } else if front_knot_step == qdir.back_left() {
+                    knot.layout = Layout::Queue(qdir.left());
+                } else if front_knot_step == qdir.back_right() {
+                    knot.layout = Layout::Queue(qdir.right());
+                } else {
+                    unreachable!("DIR: {}", front_knot_step)
+                }
+            }
+            Layout::Diag(qdir) => {
+                if front_knot_step == qdir.front() {
+                    knot.layout = Layout::Queue(qdir);
+                    knot.pos += qdir.front_right();
+                    return self._on_front_knot_moved(qdir.front_right(), knot_idx + 1);
+                } else if front_knot_step == qdir.back() {
+                    knot.layout = Layout::Queue(qdir.right());
+                } else if front_knot_step == qdir.left() {
+                    knot.layout = Layout::Queue(qdir.front());
+                } else if front_knot_step == qdir.right() {
+                    knot.layout = Layout::Queue(qdir.right());
+                    knot.pos += qdir.front_right();
+                    return self._on_front_knot_moved(qdir.front_right(), knot_idx + 1);
+                } else if front_knot_step == qdir.front_right() {
+                    knot.pos += qdir.front_right();
+                    return self._on_front_knot_moved(qdir.front_right(), knot_idx + 1);
+                } else if front_knot_step == qdir.front_left() {
+                    knot.layout = Layout::Queue(qdir.front());
+                    knot.pos += qdir.front();
+                    return self._on_front_knot_moved(qdir.front(), knot_idx + 1);
+                } else if front_knot_step == qdir.back_left() {
+                    knot.layout = Layout::Same;
+                } else if front_knot_step == qdir.back_right() {
+                    knot.layout = Layout::Queue(qdir.right());
+                    knot.pos += qdir.right();
+                    return self._on_front_knot_moved(qdir.right(), knot_idx + 1);
+                } else {
+                    unreachable!("DIR: {}", front_knot_step)
+                }
+            }
+        }
+        self
+    }
+
+    fn step(mut rope: Rope, dir: Point) -> Rope {
+        rope.head += dir;
+        Rope::_on_front_knot_moved(rope, dir, 0)
+    }
+}
+
+fn task_2(moves: &[Move]) -> usize {
+    let mut motion = Motion::new(moves, 9);
+    while motion.step() {}
+
+    motion.visited.len()
+}
+
+/// Pulls a rope through the moves, one step of the head at a time.
+struct Motion<'a> {
+    moves: &'a [Move],
+    next: usize, // index of the move in progress
+    done: u32,   // steps already made of it
+    rope: Rope,
+    visited: HashSet<Point>,
+}
+
+impl<'a> Motion<'a> {
+    fn new(moves: &'a [Move], tail_len: usize) -> Self {
+        let rope = Rope::new(tail_len);
+        let visited = HashSet::from([rope.last_knot()]);
+        Self {
+            moves,
+            next: 0,
+            done: 0,
+            rope,
+            visited,
+        }
+    }
+}
+
+impl Simulation for Motion<'_> {
+    fn step(&mut self) -> bool {
+        while self
+            .moves
+            .get(self.next)
+            .is_some_and(|mv| self.done == mv.n)
+        {
+            self.next += 1;
+            self.done = 0;
+        }
+        let Some(mv) = self.moves.get(self.next) else {
+            return false;
+        };
+
+        trace!("STEP: {:?}", mv.dir);
+        self.rope = Rope::step(mem::take(&mut self.rope), mv.dir);
+        self.visited.insert(self.rope.last_knot());
+        self.done += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "{} positions visited by the tail\n{}",
+            self.visited.len(),
+            self.rope.draw(self.rope.head, 40, 120, &self.visited)
+        )
+    }
+}
+
+type Point = Vec2<i32>;
+
+/// Directions relative to a heading: `front` is the heading itself and
+/// `right` is the heading turned clockwise.
+trait Heading {
+    fn front(&self) -> Self;
+    fn back(&self) -> Self;
+    fn right(&self) -> Self;
+    fn left(&self) -> Self;
+    fn front_right(&self) -> Self;
+    fn front_left(&self) -> Self;
+    fn back_right(&self) -> Self;
+    fn back_left(&self) -> Self;
+}
+
+impl Heading for Point {
+    fn front(&self) -> Point {
+        *self
+    }
+    fn back(&self) -> Point {
+        self.rot180()
+    }
+    fn right(&self) -> Point {
+        self.rot_cw()
+    }
+    fn left(&self) -> Point {
+        self.rot_ccw()
+    }
+    fn front_right(&self) -> Point {
+        self.front() + self.right()
+    }
+    fn front_left(&self) -> Point {
+        self.front() + self.left()
+    }
+    fn back_right(&self) -> Point {
+        self.back() + self.right()
+    }
+    fn back_left(&self) -> Point {
+        self.back() + self.left()
+    }
+}
+
+pub struct Move {
+    dir: Point,
+    n: u32,
+}
+
+#[derive(Debug)]
+enum Layout {
+    Same,
+    Queue(Point),
+    Diag(Point),
+}
+
+fn parse_moves(content: &str) -> Result<Vec<Move>, ParseError> {
+    parse_lines(content, parse_move)
+}
+
+fn parse_move(line: &str) -> Result<Move, ParseError> {
+    let mut tokens = Tokens::new(line);
+    let dir = tokens.parse_with("one of 'R', 'L', 'U', 'D'", |word| match word {
+        "R" => Some(Point::new(1, 0)),
+        "L" => Some(Point::new(-1, 0)),
+        "U" => Some(Point::new(0, -1)),
+        "D" => Some(Point::new(0, 1)),
+        _ => None,
+    })?;
+    let n = tokens.number()?;
+    tokens.finish()?;
+    Ok(Move { dir, n })
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Move>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_moves(content)
+    }
+
+    fn part1(moves: &Self::Input) -> impl Display {
+        task_1(moves)
+    }
+
+    fn part2(moves: &Self::Input) -> impl Display {
+        task_2(moves)
+    }
+}
+
+impl Visualize for Day9 {
+    fn simulation(moves: &Self::Input, part: u32) -> Box<dyn Simulation + '_> {
+        let tail_len = if part == 1 { 1 } else { 9 };
+        Box::new(Motion::new(moves, tail_len))
+    }
+}
+
+/// Generates random head motions.
+#[cfg(any(test, feature = "gen"))]
+pub fn generate(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let moves: Vec<String> = (0..rng.random_range(1..=200))
+        .map(|_| {
+            let dir = ["R", "L", "U", "D"][rng.random_range(0..4)];
+            format!("{} {}", dir, rng.random_range(1..=20))
+        })
+        .collect();
+    moves.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Moves every knot towards the one in front of it after each step of the head
+    fn naive(moves: &[Move], n_knots: usize) -> usize {
+        let mut knots = vec![Point::zero(); n_knots];
+        let mut visited = HashSet::from([Point::zero()]);
+        for mv in moves {
+            for _ in 0..mv.n {
+                knots[0] += mv.dir;
+                for i in 1..n_knots {
+                    if knots[i - 1].chebyshev(knots[i]) > 1 {
+                        let pull = (knots[i - 1] - knots[i]).signum();
+                        knots[i] += pull;
+                    }
+                }
+                visited.insert(knots[n_knots - 1]);
+            }
+        }
+        visited.len()
+    }
+
+    const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    const LARGER_EXAMPLE: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input).to_string(), "13");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input).to_string(), "1");
+    }
+
+    #[test]
+    fn part2_larger_example() {
+        let input = Day9::parse(LARGER_EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input).to_string(), "36");
+    }
+
+    #[test]
+    fn rope_step_pulls_knots_behind_head() {
+        let mut rope = Rope::new(9);
+        for _ in 0..4 {
+            rope = Rope::step(rope, Point::new(1, 0));
+        }
+
+        assert_eq!(rope.head, Point::new(4, 0));
+        let knots: Vec<Point> = rope.tail.iter().map(|knot| knot.pos).collect();
+        assert_eq!(
+            knots[..4],
+            [
+                Point::new(3, 0),
+                Point::new(2, 0),
+                Point::new(1, 0),
+                Point::zero()
+            ]
+        );
+        assert!(knots[4..].iter().all(|&knot| knot == Point::zero()));
+    }
+
+    #[test]
+    fn rope_step_keeps_knots_adjacent() {
+        let mut rope = Rope::new(9);
+        for mv in Day9::parse(LARGER_EXAMPLE).unwrap() {
+            for _ in 0..mv.n {
+                rope = Rope::step(rope, mv.dir);
+
+                let mut front = rope.head;
+                for knot in &rope.tail {
+                    assert!(
+                        front.chebyshev(knot.pos) <= 1,
+                        "{} is detached from {}",
+                        knot.pos,
+                        front
+                    );
+                    front = knot.pos;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn motion_with_short_rope_matches_part1() {
+        let moves = Day9::parse(LARGER_EXAMPLE).unwrap();
+        let mut motion = Motion::new(&moves, 1);
+        while motion.step() {}
+        assert_eq!(motion.visited.len(), task_1(&moves));
+    }
+
+    #[test]
+    fn rejects_malformed_moves() {
+        let err = Day9::parse("R 4\nX 4").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "one of 'R', 'L', 'U', 'D'");
+        let err = Day9::parse("R four").err().unwrap();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (1, 3, "number")
+        );
+        let err = Day9::parse("R\n").err().unwrap();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (1, 2, "number")
+        );
+        let err = Day9::parse("U 1 2").err().unwrap();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (1, 5, "end of line")
+        );
+    }
+
+    #[test]
+    fn crlf_example() {
+        aoc_core::assert_crlf_agnostic::<Day9>(EXAMPLE);
+    }
+
+    proptest! {
+        #[test]
+        fn part1_matches_naive(seed in any::<u64>()) {
+            let moves = Day9::parse(&generate(seed)).unwrap();
+            prop_assert_eq!(task_1(&moves), naive(&moves, 2));
+        }
+
+        #[test]
+        fn part2_matches_naive(seed in any::<u64>()) {
+            let moves = Day9::parse(&generate(seed)).unwrap();
+            prop_assert_eq!(task_2(&moves), naive(&moves, 10));
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use aoc_core::{parse_lines, ParseError, Solution};
+use std::{collections::HashMap, fmt::Display};
+
+const DIGITS: [(&str, u32); 9] = [
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const DIGIT_NAMES: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+fn task_1(lines: &[String]) -> u32 {
+    let trie = Trie::new(DIGITS);
+    lines
+        .iter()
+        .map(|line| calibration_value(line, &trie))
+        .sum()
+}
+
+fn task_2(lines: &[String]) -> u32 {
+    let trie = Trie::new(DIGITS.into_iter().chain(DIGIT_NAMES));
+    lines
+        .iter()
+        .map(|line| calibration_value(line, &trie))
+        .sum()
+}
+
+/// First and last digits of the line as a two-digit number, 0 if there are no digits.
+///
+/// Every position is matched separately, so words sharing letters ("oneight") yield
+/// both digits.
+fn calibration_value(line: &str, trie: &Trie) -> u32 {
+    let line = line.as_bytes();
+    let mut digits = (0..line.len()).filter_map(|start| trie.match_at(&line[start..]));
+
+    let Some(first) = digits.next() else {
+        return 0;
+    };
+    let last = digits.next_back().unwrap_or(first);
+    first * 10 + last
+}
+
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<u8, usize>,
+    value: Option<u32>,
+}
+
+/// Prefix tree of the words to look for, each with its value.
+struct Trie {
+    nodes: Vec<TrieNode>,
+}
+
+impl Trie {
+    fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        let mut trie = Self {
+            nodes: vec![TrieNode::default()],
+        };
+        for (word, value) in words {
+            trie.insert(word, value);
+        }
+        trie
+    }
+
+    fn insert(&mut self, word: &str, value: u32) {
+        let mut node = 0;
+        for &b in word.as_bytes() {
+            node = match self.nodes[node].children.get(&b) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(b, child);
+                    child
+                }
+            };
+        }
+        self.nodes[node].value = Some(value);
+    }
+
+    /// Value of the word `text` starts with.
+    fn match_at(&self, text: &[u8]) -> Option<u32> {
+        let mut node = 0;
+        for b in text {
+            node = *self.nodes[node].children.get(b)?;
+            if let Some(value) = self.nodes[node].value {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+fn parse_line(line: &str) -> Result<String, ParseError> {
+    match line
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_alphanumeric())
+    {
+        Some((idx, c)) => Err(ParseError::at_token(
+            line,
+            &line[idx..idx + c.len_utf8()],
+            "letter or digit",
+        )),
+        None => Ok(line.to_string()),
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(content, parse_line)
+    }
+
+    fn part1(lines: &Self::Input) -> impl Display {
+        task_1(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> impl Display {
+        task_2(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    const WORDS_EXAMPLE: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    const INPUT: &str = include_str!("../input.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input).to_string(), "142");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(WORDS_EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input).to_string(), "281");
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let trie = Trie::new(DIGITS.into_iter().chain(DIGIT_NAMES));
+        assert_eq!(calibration_value("oneight", &trie), 18);
+        assert_eq!(calibration_value("twone", &trie), 21);
+        assert_eq!(calibration_value("eightwo", &trie), 82);
+        assert_eq!(calibration_value("sevenine", &trie), 79);
+        assert_eq!(calibration_value("nineight", &trie), 98);
+        assert_eq!(calibration_value("5", &trie), 55);
+        assert_eq!(calibration_value("xyz", &trie), 0);
+    }
+
+    /// Answers of the Python scripts in `task-1` and `task-2`.
+    #[test]
+    fn same_answers_as_python() {
+        let input = Day1::parse(INPUT).unwrap();
+        assert_eq!(Day1::part1(&input).to_string(), "52974");
+        assert_eq!(Day1::part2(&input).to_string(), "53340");
+    }
+
+    #[test]
+    fn crlf_example() {
+        let crlf = WORDS_EXAMPLE.replace('\n', "\r\n") + "\r\n\r\n";
+        let input = Day1::parse(&crlf).unwrap();
+        assert_eq!(Day1::part2(&input).to_string(), "281");
+    }
+
+    #[test]
+    fn rejects_other_characters() {
+        let err = Day1::parse("ab1\nfour 2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+}
@@ -0,0 +1,7 @@
+mod parse;
+mod simulation;
+mod solution;
+
+pub use parse::{parse_lines, ParseError, Tokens};
+pub use simulation::{Simulation, Visualize};
+pub use solution::{assert_crlf_agnostic, Solution};
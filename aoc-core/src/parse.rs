@@ -0,0 +1,182 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Malformed puzzle input: what was expected and what was found instead.
+///
+/// Parsers working on a single line only know the column; the line number and
+/// text are attached by the caller with [`ParseError::at_line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number, 0 if the error has not been located yet.
+    pub line: usize,
+    /// 1-based column (in chars) of the offending text.
+    pub column: usize,
+    /// Width (in chars) of the offending text, at least 1.
+    pub len: usize,
+    pub expected: String,
+    pub found: String,
+    /// The whole input line the error points into.
+    pub source: String,
+}
+
+impl ParseError {
+    pub fn new(
+        column: usize,
+        len: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            line: 0,
+            column,
+            len: len.max(1),
+            expected: expected.into(),
+            found: found.into(),
+            source: String::new(),
+        }
+    }
+
+    /// Error about the `token` slice of `line`.
+    pub fn at_token(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+        assert!(offset <= line.len(), "token does not belong to the line");
+
+        let column = line[..offset].chars().count() + 1;
+        Self::new(
+            column,
+            token.chars().count(),
+            expected,
+            format!("'{}'", token),
+        )
+        .with_source(line)
+    }
+
+    /// Error about the end of `line`, when more was expected.
+    pub fn at_end(line: &str, expected: impl Into<String>) -> Self {
+        Self::new(line.chars().count() + 1, 1, expected, "end of line").with_source(line)
+    }
+
+    /// Locates the error at the given 1-based line of the input.
+    pub fn at_line(mut self, line: usize, source: &str) -> Self {
+        self.line = line;
+        self.source = source.to_string();
+        self
+    }
+
+    fn with_source(mut self, source: &str) -> Self {
+        self.source = source.to_string();
+        self
+    }
+
+    /// Compiler-style diagnostic pointing a caret at the offending text.
+    pub fn render(&self, path: &str) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column - 1);
+        let carets = "^".repeat(self.len);
+
+        format!(
+            "error: expected {}, found {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.expected,
+            self.found,
+            gutter,
+            path,
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            self.source,
+            gutter,
+            padding,
+            carets,
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every line of `content` with `f`, locating errors at the failing line.
+/// Lines may end with `\n` or `\r\n`; empty lines at the end are ignored.
+pub fn parse_lines<T, F>(content: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    content
+        .trim_end_matches(['\r', '\n'])
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| f(line).map_err(|err| err.at_line(idx + 1, line)))
+        .collect()
+}
+
+/// Whitespace separated tokens of a single line that keep track of their columns.
+pub struct Tokens<'a> {
+    line: &'a str,
+    words: std::str::SplitWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Self {
+            line,
+            words: line.split_whitespace(),
+        }
+    }
+
+    /// Next token, whatever it is.
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        self.words
+            .next()
+            .ok_or_else(|| ParseError::at_end(self.line, expected))
+    }
+
+    /// Skips the next token, which must be exactly `literal`.
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        let expected = format!("'{}'", literal);
+        let word = self.word(&expected)?;
+        if word != literal {
+            return Err(self.error(word, expected));
+        }
+        Ok(())
+    }
+
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let word = self.word("number")?;
+        word.parse().map_err(|_| self.error(word, "number"))
+    }
+
+    /// Next token converted with `f`, which returns `None` if the token is not `expected`.
+    pub fn parse_with<T, F>(&mut self, expected: &str, f: F) -> Result<T, ParseError>
+    where
+        F: FnOnce(&'a str) -> Option<T>,
+    {
+        let word = self.word(expected)?;
+        f(word).ok_or_else(|| self.error(word, expected))
+    }
+
+    /// Remaining tokens.
+    pub fn rest(&mut self) -> impl Iterator<Item = &'a str> + '_ {
+        &mut self.words
+    }
+
+    /// Fails if there are tokens left on the line.
+    pub fn finish(mut self) -> Result<(), ParseError> {
+        match self.words.next() {
+            Some(word) => Err(self.error(word, "end of line")),
+            None => Ok(()),
+        }
+    }
+
+    /// Error about `token`, which must be a slice of this line.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at_token(self.line, token, expected)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::Solution;
+
+/// A puzzle state that can be advanced and drawn one frame at a time.
+pub trait Simulation {
+    /// Advances the simulation by one frame, returns `false` once it is over.
+    fn step(&mut self) -> bool;
+
+    /// Draws the current state as lines of text: a caption line followed by the picture, one
+    /// character per cell.
+    fn render(&self) -> String;
+}
+
+/// A solution whose parts can be watched as they are computed.
+pub trait Visualize: Solution {
+    fn simulation(input: &Self::Input, part: u32) -> Box<dyn Simulation + '_>;
+}
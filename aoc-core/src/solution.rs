@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+/// A puzzle solved in two phases: the input is parsed once into `Input`,
+/// then both parts are computed from the parsed model.
+pub trait Solution {
+    type Input;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+/// Checks, for the tests of a day, that `example` saved with CRLF line endings and blank lines
+/// at the end parses and gives the same part 1 answer.
+pub fn assert_crlf_agnostic<S: Solution>(example: &str) {
+    let crlf = example.replace('\n', "\r\n") + "\r\n\r\n";
+    let answer = |content: &str| match S::parse(content) {
+        Ok(input) => S::part1(&input).to_string(),
+        Err(err) => panic!("{}", err),
+    };
+    assert_eq!(answer(&crlf), answer(example));
+}
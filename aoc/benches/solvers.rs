@@ -0,0 +1,191 @@
+//! Times parsing and both parts of every day that has a stored input
+//! (`YEAR/dayDAY/input.txt` or a downloaded one), then prints a summary table:
+//!
+//!     cargo bench -p aoc --bench solvers [-- 2022-day15]
+//!
+//! The table is also written to `target/criterion/summary.txt`, so it can be
+//! kept and compared with the one of another commit.
+
+use aoc::{
+    days::{self, Day},
+    input::{Client, Config},
+    memory::{self, Usage},
+};
+use criterion::Criterion;
+use serde::Deserialize;
+use std::{
+    env, fs,
+    hint::black_box,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+/// Memory allocated by a single run of `f`.
+fn count_allocs<T>(f: impl FnOnce() -> T) -> Usage {
+    memory::measure(|| black_box(f())).1
+}
+
+struct Row {
+    group: String,
+    phase: &'static str,
+    allocs: Option<Usage>,
+}
+
+fn bench_day(c: &mut Criterion, day: &Day, content: &str, rows: &mut Vec<Row>) {
+    let group_id = format!("{}-day{}", day.year, day.day);
+    let input = match (day.parse)(content) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Skipping {}: {}", group_id, err);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(&group_id);
+
+    let mut allocs = None;
+    group.bench_function("parse", |b| {
+        allocs.get_or_insert_with(|| count_allocs(|| (day.parse)(content)));
+        b.iter(|| (day.parse)(black_box(content)))
+    });
+    rows.push(Row {
+        group: group_id.clone(),
+        phase: "parse",
+        allocs,
+    });
+
+    for (part, phase) in [(1, "part1"), (2, "part2")] {
+        let mut allocs = None;
+        // A part that panics, e.g. one not solved yet, does so on its first run, before criterion
+        // records the benchmark, and is left out instead of aborting every other benchmark
+        let bench = panic::catch_unwind(AssertUnwindSafe(|| {
+            group.bench_function(phase, |b| {
+                allocs.get_or_insert_with(|| count_allocs(|| (day.part)(input.as_ref(), part)));
+                b.iter(|| (day.part)(black_box(input.as_ref()), part))
+            });
+        }));
+        if bench.is_err() {
+            eprintln!("Skipping {}/{}: it panicked", group_id, phase);
+            continue;
+        }
+        rows.push(Row {
+            group: group_id.clone(),
+            phase,
+            allocs,
+        });
+    }
+
+    group.finish();
+}
+
+/// Where criterion keeps its results, resolved the same way criterion does.
+fn criterion_dir() -> PathBuf {
+    if let Some(home) = env::var_os("CRITERION_HOME") {
+        PathBuf::from(home)
+    } else if let Some(target) = env::var_os("CARGO_TARGET_DIR") {
+        PathBuf::from(target).join("criterion")
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/criterion")
+    }
+}
+
+#[derive(Deserialize)]
+struct Sample {
+    iters: Vec<f64>,
+    times: Vec<f64>,
+}
+
+/// Sorted per-iteration times (ns) measured by this run, if the benchmark ran.
+fn load_sample(dir: &Path, row: &Row, since: SystemTime) -> Option<Vec<f64>> {
+    let path = dir.join(&row.group).join(row.phase).join("new/sample.json");
+    if fs::metadata(&path).ok()?.modified().ok()? < since {
+        return None;
+    }
+
+    let sample: Sample = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    let mut times: Vec<f64> = sample
+        .iters
+        .iter()
+        .zip(&sample.times)
+        .map(|(iters, time)| time / iters)
+        .collect();
+    times.sort_by(f64::total_cmp);
+    Some(times)
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    sorted[((sorted.len() - 1) as f64 * p).round() as usize]
+}
+
+fn format_time(ns: f64) -> String {
+    match ns {
+        ns if ns < 1e3 => format!("{:.1} ns", ns),
+        ns if ns < 1e6 => format!("{:.1} µs", ns / 1e3),
+        ns if ns < 1e9 => format!("{:.1} ms", ns / 1e6),
+        ns => format!("{:.2} s", ns / 1e9),
+    }
+}
+
+fn summary(rows: &[Row], dir: &Path, since: SystemTime) -> String {
+    let mut table = format!(
+        "{:<12} {:<6} {:>12} {:>12} {:>10} {:>12}\n",
+        "day", "phase", "median", "p95", "allocs", "bytes"
+    );
+    for row in rows {
+        let Some(times) = load_sample(dir, row, since) else {
+            continue;
+        };
+        let (count, bytes) = match row.allocs {
+            Some(allocs) => (allocs.allocations.to_string(), allocs.bytes.to_string()),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        table += &format!(
+            "{:<12} {:<6} {:>12} {:>12} {:>10} {:>12}\n",
+            row.group,
+            row.phase,
+            format_time(percentile(&times, 0.5)),
+            format_time(percentile(&times, 0.95)),
+            count,
+            bytes
+        );
+    }
+    table
+}
+
+fn main() {
+    aoc::logging::init(0);
+    memory::enable();
+    let started = SystemTime::now();
+    let mut criterion = Criterion::default().sample_size(10).configure_from_args();
+
+    let inputs = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let client = Config::load().ok().map(Client::new);
+    let mut rows = Vec::new();
+    for day in days::DAYS {
+        let path = inputs.join(format!("{}/day{}/input.txt", day.year, day.day));
+        let content = fs::read_to_string(path)
+            .ok()
+            .or_else(|| client.as_ref()?.cached(day.year, day.day));
+        if let Some(content) = content {
+            bench_day(&mut criterion, day, &content, &mut rows);
+        }
+    }
+
+    criterion.final_summary();
+
+    if rows.is_empty() {
+        println!("No stored inputs to benchmark");
+        return;
+    }
+
+    let dir = criterion_dir();
+    let table = summary(&rows, &dir, started);
+    print!("\n{}", table);
+    if let Err(err) = fs::write(dir.join("summary.txt"), &table) {
+        eprintln!("Cannot write the summary: {}", err);
+    }
+}
@@ -0,0 +1,219 @@
+use aoc_core::Simulation;
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::{
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+/// How often the screen is redrawn while playing
+const REFRESH: Duration = Duration::from_millis(33);
+
+const MAX_SPEED: u32 = 1 << 16;
+
+/// Plays the simulation in the terminal until it is quit with `q`. It starts playing at 10
+/// frames per second; space pauses and resumes, `s` or → steps a single frame, `+`/`-` or ↑/↓
+/// double and halve the speed.
+pub fn play(sim: &mut dyn Simulation) -> io::Result<()> {
+    let mut out = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(out, EnterAlternateScreen, Hide)?;
+
+    let result = run(sim, &mut out);
+
+    execute!(out, Show, LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn run(sim: &mut dyn Simulation, out: &mut impl Write) -> io::Result<()> {
+    let mut player = Player::new();
+    let mut frame = 0;
+    let mut over = false;
+    let mut last = Instant::now();
+
+    loop {
+        draw(out, &sim.render(), &player.status(frame, over))?;
+
+        // Paused, nothing changes until a key is pressed
+        let event = if !player.playing || over || event::poll(REFRESH)? {
+            Some(event::read()?)
+        } else {
+            None
+        };
+
+        let mut steps = 0;
+        if let Some(Event::Key(key)) = event {
+            match command(key) {
+                Some(Command::Quit) => return Ok(()),
+                Some(command) => steps += player.apply(command),
+                None => (),
+            }
+        }
+        steps += player.frames_due(last.elapsed());
+        last = Instant::now();
+
+        for _ in 0..steps {
+            if over {
+                break;
+            }
+            over = !sim.step();
+            frame += 1;
+        }
+    }
+}
+
+fn draw(out: &mut impl Write, frame: &str, status: &str) -> io::Result<()> {
+    let (cols, rows) = terminal::size()?;
+    let visible = |line: &str| line.chars().take(cols as usize).collect::<String>();
+
+    let mut y = 0;
+    for line in frame.lines().take(rows.saturating_sub(1) as usize) {
+        queue!(
+            out,
+            MoveTo(0, y),
+            Print(visible(line)),
+            Clear(ClearType::UntilNewLine)
+        )?;
+        y += 1;
+    }
+    queue!(out, MoveTo(0, y), Clear(ClearType::FromCursorDown))?;
+    queue!(
+        out,
+        MoveTo(0, rows.saturating_sub(1)),
+        Print(visible(status))
+    )?;
+    out.flush()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Command {
+    Quit,
+    Toggle,
+    Step,
+    Faster,
+    Slower,
+}
+
+fn command(key: KeyEvent) -> Option<Command> {
+    if key.kind == KeyEventKind::Release {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Command::Quit),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Command::Quit),
+        KeyCode::Char(' ') => Some(Command::Toggle),
+        KeyCode::Char('s') | KeyCode::Right => Some(Command::Step),
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Some(Command::Faster),
+        KeyCode::Char('-') | KeyCode::Down => Some(Command::Slower),
+        _ => None,
+    }
+}
+
+/// Playback state: whether frames advance on their own and how fast.
+struct Player {
+    playing: bool,
+    speed: u32,
+    due: f64, // fraction of a frame carried over to the next refresh
+}
+
+impl Player {
+    fn new() -> Self {
+        Self {
+            playing: true,
+            speed: 10,
+            due: 0.0,
+        }
+    }
+
+    /// Applies a command, returns the number of frames to advance right away.
+    fn apply(&mut self, command: Command) -> u64 {
+        match command {
+            Command::Toggle => self.playing = !self.playing,
+            Command::Step => {
+                self.playing = false;
+                return 1;
+            }
+            Command::Faster => self.speed = (self.speed * 2).min(MAX_SPEED),
+            Command::Slower => self.speed = (self.speed / 2).max(1),
+            Command::Quit => (),
+        }
+        0
+    }
+
+    /// Number of frames to advance after `elapsed` time, at most a second worth of them.
+    fn frames_due(&mut self, elapsed: Duration) -> u64 {
+        if !self.playing {
+            self.due = 0.0;
+            return 0;
+        }
+        self.due += elapsed.as_secs_f64() * self.speed as f64;
+        let frames = self.due.floor().min(self.speed as f64);
+        self.due -= frames;
+        self.due = self.due.min(1.0);
+        frames as u64
+    }
+
+    fn status(&self, frame: u64, over: bool) -> String {
+        let state = match (over, self.playing) {
+            (true, _) => "finished",
+            (false, true) => "playing",
+            (false, false) => "paused",
+        };
+        format!(
+            "frame {} | {} fps | {} | space: play/pause, s: step, +/-: speed, q: quit",
+            frame, self.speed, state
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_keys_to_commands() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert_eq!(command(key(KeyCode::Char('q'))), Some(Command::Quit));
+        assert_eq!(command(key(KeyCode::Char(' '))), Some(Command::Toggle));
+        assert_eq!(command(key(KeyCode::Right)), Some(Command::Step));
+        assert_eq!(command(key(KeyCode::Char('+'))), Some(Command::Faster));
+        assert_eq!(command(key(KeyCode::Down)), Some(Command::Slower));
+        assert_eq!(command(key(KeyCode::Char('x'))), None);
+        assert_eq!(
+            command(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Command::Quit)
+        );
+    }
+
+    #[test]
+    fn paces_frames_by_speed() {
+        let mut player = Player::new();
+        assert_eq!(player.frames_due(Duration::from_millis(250)), 2);
+        assert_eq!(player.frames_due(Duration::from_millis(50)), 1);
+
+        player.apply(Command::Faster);
+        assert_eq!(player.speed, 20);
+        assert_eq!(player.frames_due(Duration::from_secs(10)), 20);
+
+        player.apply(Command::Toggle);
+        assert_eq!(player.frames_due(Duration::from_secs(1)), 0);
+    }
+
+    #[test]
+    fn stepping_pauses() {
+        let mut player = Player::new();
+        assert_eq!(player.apply(Command::Step), 1);
+        assert!(!player.playing);
+
+        for _ in 0..10 {
+            player.apply(Command::Slower);
+        }
+        assert_eq!(player.speed, 1);
+    }
+}
@@ -0,0 +1,244 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+const HEADER: &str = "\
+# Answers given to the site, keyed by year, day and part.
+#
+#   accepted  the answer that was accepted
+#   wrong     answers that were rejected
+#   too_high  smallest answer known to be too high
+#   too_low   largest answer known to be too low
+
+";
+
+/// What is known about the answer of a single part.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PartAnswers {
+    /// The answer the site accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepted: Option<String>,
+    /// Answers the site rejected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+    /// Smallest answer known to be too high.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i64>,
+    /// Largest answer known to be too low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i64>,
+}
+
+/// Outcome of checking an answer against the ledger.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    /// Matches the accepted answer.
+    Pass,
+    /// Differs from the accepted answer or is known to be wrong.
+    Fail(String),
+    /// Nothing contradicts the answer, but it has not been accepted yet.
+    New,
+}
+
+impl PartAnswers {
+    pub fn accept(&mut self, answer: &str) {
+        self.accepted = Some(answer.to_string());
+    }
+
+    pub fn reject(&mut self, answer: &str) {
+        if !self.wrong.iter().any(|wrong| wrong == answer) {
+            self.wrong.push(answer.to_string());
+        }
+    }
+
+    pub fn reject_too_high(&mut self, answer: &str) {
+        self.reject(answer);
+        if let Ok(n) = answer.parse::<i64>() {
+            self.too_high = Some(self.too_high.map_or(n, |too_high| too_high.min(n)));
+        }
+    }
+
+    pub fn reject_too_low(&mut self, answer: &str) {
+        self.reject(answer);
+        if let Ok(n) = answer.parse::<i64>() {
+            self.too_low = Some(self.too_low.map_or(n, |too_low| too_low.max(n)));
+        }
+    }
+
+    pub fn check(&self, answer: &str) -> Verdict {
+        if let Some(accepted) = &self.accepted {
+            return if accepted == answer {
+                Verdict::Pass
+            } else {
+                Verdict::Fail(diff(accepted, answer))
+            };
+        }
+
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            return Verdict::Fail(format!("{} was already rejected", answer));
+        }
+
+        if let Ok(n) = answer.parse::<i64>() {
+            if let Some(too_high) = self.too_high.filter(|&too_high| n >= too_high) {
+                return Verdict::Fail(format!("{} is too high (>= {})", n, too_high));
+            }
+            if let Some(too_low) = self.too_low.filter(|&too_low| n <= too_low) {
+                return Verdict::Fail(format!("{} is too low (<= {})", n, too_low));
+            }
+        }
+
+        Verdict::New
+    }
+}
+
+/// Answers keyed by year, day and part: `[2022.21.2]` in the TOML file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Ledger {
+    years: BTreeMap<String, BTreeMap<String, BTreeMap<String, PartAnswers>>>,
+}
+
+impl Ledger {
+    /// Reads the ledger from `path`; a missing file is an empty ledger.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+        toml::from_str(&content).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&PartAnswers> {
+        self.years
+            .get(&year.to_string())?
+            .get(&day.to_string())?
+            .get(&part.to_string())
+    }
+
+    pub fn get_mut(&mut self, year: u32, day: u32, part: u32) -> &mut PartAnswers {
+        self.years
+            .entry(year.to_string())
+            .or_default()
+            .entry(day.to_string())
+            .or_default()
+            .entry(part.to_string())
+            .or_default()
+    }
+
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &str) -> Verdict {
+        self.get(year, day, part)
+            .map_or(Verdict::New, |answers| answers.check(answer))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content =
+            toml::to_string(self).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(path, format!("{}{}", HEADER, content))
+    }
+}
+
+/// Line by line difference, `-` for the expected lines and `+` for the actual ones.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    let mut out = Vec::new();
+    for idx in 0..expected.len().max(actual.len()) {
+        match (expected.get(idx), actual.get(idx)) {
+            (Some(e), Some(a)) if e == a => out.push(format!("  {}", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    out.push(format!("- {}", e));
+                }
+                if let Some(a) = a {
+                    out.push(format!("+ {}", a));
+                }
+            }
+        }
+    }
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEDGER: &str = r#"
+[2022.5.1]
+accepted = "CMZ"
+
+[2022.21.2]
+wrong = ["3617613952379"]
+too_high = 4000000000000
+too_low = 1000
+"#;
+
+    fn ledger() -> Ledger {
+        toml::from_str(LEDGER).unwrap()
+    }
+
+    #[test]
+    fn accepted_answer_passes() {
+        assert_eq!(ledger().check(2022, 5, 1, "CMZ"), Verdict::Pass);
+    }
+
+    #[test]
+    fn changed_answer_fails_with_diff() {
+        assert_eq!(
+            ledger().check(2022, 5, 1, "MCD"),
+            Verdict::Fail("- CMZ\n+ MCD".to_string())
+        );
+    }
+
+    #[test]
+    fn known_wrong_answers_fail() {
+        let ledger = ledger();
+        assert!(matches!(
+            ledger.check(2022, 21, 2, "3617613952379"),
+            Verdict::Fail(_)
+        ));
+        assert!(matches!(
+            ledger.check(2022, 21, 2, "4000000000000"),
+            Verdict::Fail(_)
+        ));
+        assert!(matches!(ledger.check(2022, 21, 2, "999"), Verdict::Fail(_)));
+        assert_eq!(ledger.check(2022, 21, 2, "3617613952378"), Verdict::New);
+    }
+
+    #[test]
+    fn unknown_parts_are_new() {
+        assert_eq!(ledger().check(2022, 5, 2, "MCD"), Verdict::New);
+    }
+
+    #[test]
+    fn rejections_narrow_bounds() {
+        let mut answers = PartAnswers::default();
+        answers.reject_too_high("500");
+        answers.reject_too_high("700");
+        answers.reject_too_low("100");
+        answers.reject_too_low("100");
+
+        assert_eq!(answers.wrong, ["500", "700", "100"]);
+        assert_eq!(answers.too_high, Some(500));
+        assert_eq!(answers.too_low, Some(100));
+    }
+
+    #[test]
+    fn saved_ledger_loads_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+
+        let mut ledger = ledger();
+        ledger.get_mut(2022, 1, 1).accept("24000");
+        ledger.save(&path).unwrap();
+
+        let loaded = Ledger::load(&path).unwrap();
+        assert_eq!(loaded.check(2022, 1, 1, "24000"), Verdict::Pass);
+        assert_eq!(loaded.get(2022, 21, 2), ledger.get(2022, 21, 2));
+    }
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(diff("a\nb\nc", "a\nx"), "  a\n- b\n+ x\n- c");
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{
+    days::Day,
+    memory::{self, Usage},
+};
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A part of some day to compute on the given input.
+pub struct Task {
+    pub day: &'static Day,
+    pub part: u32,
+    pub content: Arc<str>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Answer(String),
+    ParseError(String),
+    Panic(String),
+    TimedOut,
+}
+
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub outcome: Outcome,
+    pub parse_time: Duration,
+    /// Time spent on the part, or the timeout if it did not finish.
+    pub part_time: Duration,
+    /// Memory allocated by parsing, all zeros unless allocations are counted.
+    pub parse_usage: Usage,
+    /// Memory allocated by the part.
+    pub part_usage: Usage,
+}
+
+/// Runs the tasks on `workers` threads, giving each of them `timeout` to finish.
+/// Reports come in the order of the tasks.
+///
+/// A task that runs out of time is left running in the background: a thread cannot be
+/// stopped, so it only ends with the process.
+pub fn run(tasks: Vec<Task>, workers: usize, timeout: Duration) -> Vec<Report> {
+    let n_tasks = tasks.len();
+    let queue = Arc::new(Mutex::new(tasks.into_iter().enumerate()));
+    let (report_tx, report_rx) = mpsc::channel();
+
+    let workers: Vec<_> = (0..workers.max(1))
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            let report_tx = report_tx.clone();
+            thread::spawn(move || loop {
+                let Some((idx, task)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                report_tx.send((idx, run_task(task, timeout))).unwrap();
+            })
+        })
+        .collect();
+    drop(report_tx);
+
+    let mut reports: Vec<Option<Report>> = (0..n_tasks).map(|_| None).collect();
+    for (idx, report) in report_rx {
+        reports[idx] = Some(report);
+    }
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    reports.into_iter().map(Option::unwrap).collect()
+}
+
+fn run_task(task: Task, timeout: Duration) -> Report {
+    let (year, day, part) = (task.day.year, task.day.day, task.part);
+    let report = move |outcome| Report {
+        year,
+        day,
+        part,
+        outcome,
+        parse_time: Duration::ZERO,
+        part_time: Duration::ZERO,
+        parse_usage: Usage::default(),
+        part_usage: Usage::default(),
+    };
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let result = catch_panic(|| {
+            let _span = task.day.span().entered();
+            let start = Instant::now();
+            let (input, parse_usage) = memory::measure(|| task.day.parse_input(&task.content));
+            let parse_time = start.elapsed();
+            let input = match input {
+                Ok(input) => input,
+                Err(err) => {
+                    return Report {
+                        parse_time,
+                        parse_usage,
+                        ..report(Outcome::ParseError(err.to_string()))
+                    }
+                }
+            };
+
+            let start = Instant::now();
+            let (answer, part_usage) = memory::measure(|| task.day.run_part(&input, task.part));
+            Report {
+                parse_time,
+                part_time: start.elapsed(),
+                parse_usage,
+                part_usage,
+                ..report(Outcome::Answer(answer))
+            }
+        });
+        let _ = tx.send(result.unwrap_or_else(|message| report(Outcome::Panic(message))));
+    });
+
+    rx.recv_timeout(timeout).unwrap_or_else(|_| Report {
+        part_time: timeout,
+        ..report(Outcome::TimedOut)
+    })
+}
+
+/// Runs `f`, turning a panic into its message.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::Parsed;
+    use aoc_core::ParseError;
+
+    fn parse(content: &str) -> Result<Parsed, ParseError> {
+        match content.parse::<u64>() {
+            Ok(n) => Ok(Box::new(n)),
+            Err(_) => Err(ParseError::at_token(content, content, "number")),
+        }
+    }
+
+    fn part(input: &dyn Any, part: u32) -> String {
+        let n = *input.downcast_ref::<u64>().unwrap();
+        match part {
+            1 => (n * 2).to_string(),
+            _ if n == 0 => todo!(),
+            _ => {
+                thread::sleep(Duration::from_millis(n));
+                "slept".to_string()
+            }
+        }
+    }
+
+    static DAY: Day = Day {
+        year: 2000,
+        day: 1,
+        parse,
+        part,
+        simulation: None,
+    };
+
+    fn task(part: u32, content: &str) -> Task {
+        Task {
+            day: &DAY,
+            part,
+            content: Arc::from(content),
+        }
+    }
+
+    #[test]
+    fn reports_every_task_in_order() {
+        let tasks = (0..10).map(|n| task(1, &n.to_string())).collect();
+        let reports = run(tasks, 3, Duration::from_secs(10));
+
+        let answers: Vec<Outcome> = reports.into_iter().map(|r| r.outcome).collect();
+        let expected: Vec<Outcome> = (0..10)
+            .map(|n| Outcome::Answer((n * 2).to_string()))
+            .collect();
+        assert_eq!(answers, expected);
+    }
+
+    #[test]
+    fn captures_failures() {
+        let tasks = vec![task(1, "x"), task(2, "0"), task(2, "5000"), task(2, "1")];
+        let reports = run(tasks, 2, Duration::from_millis(200));
+
+        assert!(matches!(reports[0].outcome, Outcome::ParseError(_)));
+        assert_eq!(
+            reports[1].outcome,
+            Outcome::Panic("not yet implemented".to_string())
+        );
+        assert_eq!(reports[2].outcome, Outcome::TimedOut);
+        assert_eq!(reports[2].part_time, Duration::from_millis(200));
+        assert_eq!(reports[3].outcome, Outcome::Answer("slept".to_string()));
+    }
+}
@@ -0,0 +1,133 @@
+use aoc_core::{ParseError, Simulation, Solution, Visualize};
+use std::{any::Any, time::Instant};
+use tracing::{info, info_span, Span};
+
+/// Parsed input of some day, only understood by the same day's parts.
+pub type Parsed = Box<dyn Any>;
+
+/// Builds the animated version of a part from the parsed input.
+pub type SimulationFn = fn(&dyn Any, u32) -> Box<dyn Simulation + '_>;
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub parse: fn(&str) -> Result<Parsed, ParseError>,
+    pub part: fn(&dyn Any, u32) -> String,
+    /// Animated version of a part, for the days that have one
+    pub simulation: Option<SimulationFn>,
+}
+
+impl Day {
+    /// Parses the input once and computes the requested parts on it.
+    pub fn solve(&self, content: &str, parts: &[u32]) -> Result<Vec<String>, ParseError> {
+        let _span = self.span().entered();
+        let input = self.parse_input(content)?;
+        Ok(parts
+            .iter()
+            .map(|&part| self.run_part(&input, part))
+            .collect())
+    }
+
+    /// Span the events of this day are reported in.
+    pub fn span(&self) -> Span {
+        info_span!("day", year = self.year, day = self.day)
+    }
+
+    pub fn parse_input(&self, content: &str) -> Result<Parsed, ParseError> {
+        let _span = info_span!("parse").entered();
+        let start = Instant::now();
+        let input = (self.parse)(content)?;
+        info!("done in {:.2?}", start.elapsed());
+        Ok(input)
+    }
+
+    pub fn run_part(&self, input: &Parsed, part: u32) -> String {
+        let _span = info_span!("part", part).entered();
+        let start = Instant::now();
+        let answer = (self.part)(input.as_ref(), part);
+        info!("done in {:.2?}", start.elapsed());
+        answer
+    }
+}
+
+fn parse<S: Solution>(content: &str) -> Result<Parsed, ParseError>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse(content)?))
+}
+
+fn part<S: Solution>(input: &dyn Any, part: u32) -> String
+where
+    S::Input: 'static,
+{
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input parsed by another day");
+    match part {
+        1 => S::part1(input).to_string(),
+        2 => S::part2(input).to_string(),
+        _ => panic!("Unexpected part: {}", part),
+    }
+}
+
+fn simulation<S: Visualize>(input: &dyn Any, part: u32) -> Box<dyn Simulation + '_>
+where
+    S::Input: 'static,
+{
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input parsed by another day");
+    S::simulation(input, part)
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:ty) => {
+        Day {
+            year: $year,
+            day: $day,
+            parse: parse::<$solution>,
+            part: part::<$solution>,
+            simulation: None,
+        }
+    };
+    ($year:literal, $day:literal, $solution:ty, visualize) => {
+        Day {
+            simulation: Some(simulation::<$solution>),
+            ..day!($year, $day, $solution)
+        }
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(2022, 1, aoc2022_day1::Day1),
+    day!(2022, 2, aoc2022_day2::Day2),
+    day!(2022, 3, aoc2022_day3::Day3),
+    day!(2022, 4, aoc2022_day4::Day4),
+    day!(2022, 5, aoc2022_day5::Day5),
+    day!(2022, 6, aoc2022_day6::Day6),
+    day!(2022, 7, aoc2022_day7::Day7),
+    day!(2022, 8, aoc2022_day8::Day8),
+    day!(2022, 9, aoc2022_day9::Day9, visualize),
+    day!(2022, 10, aoc2022_day10::Day10, visualize),
+    day!(2022, 11, aoc2022_day11::Day11),
+    day!(2022, 12, aoc2022_day12::Day12),
+    day!(2022, 13, aoc2022_day13::Day13),
+    day!(2022, 14, aoc2022_day14::Day14, visualize),
+    day!(2022, 15, aoc2022_day15::Day15),
+    day!(2022, 16, aoc2022_day16::Day16),
+    day!(2022, 17, aoc2022_day17::Day17, visualize),
+    day!(2022, 18, aoc2022_day18::Day18),
+    day!(2022, 19, aoc2022_day19::Day19),
+    day!(2022, 20, aoc2022_day20::Day20),
+    day!(2022, 21, aoc2022_day21::Day21),
+    day!(2022, 22, aoc2022_day22::Day22, visualize),
+    day!(2022, 23, aoc2022_day23::Day23, visualize),
+    day!(2022, 24, aoc2022_day24::Day24, visualize),
+    day!(2022, 25, aoc2022_day25::Day25),
+    day!(2023, 1, aoc2023_day1::Day1),
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
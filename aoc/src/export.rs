@@ -0,0 +1,448 @@
+use aoc_core::Simulation;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter},
+    path::Path,
+    str::FromStr,
+};
+
+/// Delay between the frames of a GIF, in hundredths of a second
+const FRAME_DELAY: u16 = 10;
+
+/// Steps made at most for a PNG, some simulations (day 17 part 2) go on for ages
+const MAX_STEPS: usize = 100_000_000;
+
+/// Colours a GIF frame can use
+const GIF_COLORS: usize = 256;
+
+type Rgb = [u8; 3];
+
+/// Colours of the cells of rendered frames, by the character they are drawn with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    /// Colour of the characters without one of their own
+    other: Rgb,
+}
+
+impl Palette {
+    fn color(&self, ch: char) -> Rgb {
+        self.colors.get(&ch).copied().unwrap_or(self.other)
+    }
+
+    /// Sets the colours given as `c=rrggbb` entries separated by commas, e.g. `o=c2b280,#=404040`.
+    pub fn merge(&mut self, spec: &str) -> Result<(), String> {
+        let mut chars = spec.chars();
+        loop {
+            let entry: String = chars.by_ref().take(8).collect();
+            let mut entry_chars = entry.chars();
+            let (Some(ch), Some('='), hex) = (entry_chars.next(), entry_chars.next(), entry_chars)
+            else {
+                return Err(format!("expected 'c=rrggbb', found '{}'", entry));
+            };
+            let hex = hex.as_str();
+            let color = u32::from_str_radix(hex, 16)
+                .ok()
+                .filter(|_| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+                .ok_or_else(|| format!("invalid colour '{}' of '{}'", hex, ch))?;
+            let [_, r, g, b] = color.to_be_bytes();
+            self.colors.insert(ch, [r, g, b]);
+
+            match chars.next() {
+                None => return Ok(()),
+                Some(',') => (),
+                Some(other) => return Err(format!("expected ',', found '{}'", other)),
+            }
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        let background = [24, 24, 32];
+        let colors = [
+            ('.', background),
+            (' ', background),
+            ('#', [150, 150, 150]), // walls, rocks, elves, lit pixels, visited cells
+            ('|', [90, 90, 90]),
+            ('-', [90, 90, 90]),
+            ('+', [230, 70, 70]),   // source of sand, corners of the chamber
+            ('o', [210, 180, 110]), // sand, the expedition's path
+            ('@', [240, 200, 60]),  // falling rock
+            ('E', [90, 220, 110]),  // expedition
+            ('B', [110, 170, 250]), // blizzards
+            ('H', [240, 90, 90]),   // head of the rope
+            ('>', [240, 200, 60]),
+            ('<', [240, 200, 60]),
+            ('^', [240, 200, 60]),
+            ('v', [240, 200, 60]),
+        ];
+        Self {
+            colors: colors.into_iter().collect(),
+            other: [235, 235, 235],
+        }
+    }
+}
+
+impl FromStr for Palette {
+    type Err = String;
+
+    /// The default palette with the given colours changed, see [`Palette::merge`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut palette = Palette::default();
+        palette.merge(s)?;
+        Ok(palette)
+    }
+}
+
+pub struct Options {
+    /// Size of a cell in pixels
+    pub scale: u32,
+    /// Number of frames of a GIF left out after each exported one
+    pub skip: usize,
+    /// Frames of a GIF exported at most, the simulation is cut short after them
+    pub max_frames: usize,
+    pub palette: Palette,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            scale: 4,
+            skip: 0,
+            max_frames: 1000,
+            palette: Palette::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    /// The output is neither a `.gif` nor a `.png` file.
+    Format(String),
+    /// The frames do not fit into an image.
+    TooLarge(usize, usize),
+    /// The palette has more colours than a GIF can use.
+    TooManyColors(usize),
+    Gif(gif::EncodingError),
+    Png(png::EncodingError),
+    Io(io::Error),
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::Format(path) => {
+                write!(f, "cannot tell the format of {}, use .gif or .png", path)
+            }
+            ExportError::TooLarge(w, h) => write!(f, "image of {}x{} pixels is too large", w, h),
+            ExportError::TooManyColors(n) => {
+                write!(
+                    f,
+                    "{} colours do not fit into a GIF, at most {} do",
+                    n, GIF_COLORS
+                )
+            }
+            ExportError::Gif(err) => write!(f, "{}", err),
+            ExportError::Png(err) => write!(f, "{}", err),
+            ExportError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for ExportError {}
+
+impl From<io::Error> for ExportError {
+    fn from(err: io::Error) -> Self {
+        ExportError::Io(err)
+    }
+}
+
+impl From<gif::EncodingError> for ExportError {
+    fn from(err: gif::EncodingError) -> Self {
+        ExportError::Gif(err)
+    }
+}
+
+impl From<png::EncodingError> for ExportError {
+    fn from(err: png::EncodingError) -> Self {
+        ExportError::Png(err)
+    }
+}
+
+/// Runs the simulation and writes its frames to `path`: all of them as an animated GIF for
+/// `.gif`, the one of the state it ends in for `.png`. Returns the number of frames written.
+pub fn export(
+    sim: &mut dyn Simulation,
+    path: &Path,
+    options: &Options,
+) -> Result<usize, ExportError> {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    let scale = options.scale.max(1) as usize;
+    match extension.map(str::to_ascii_lowercase).as_deref() {
+        Some("gif") => {
+            let colors = gif_colors(&options.palette)?;
+            let frames = record(sim, options);
+            let size = image_size(&frames, scale, u16::MAX as usize)?;
+            let out = BufWriter::new(File::create(path)?);
+            write_gif(out, &frames, size, scale, &colors, &options.palette)?;
+            Ok(frames.len())
+        }
+        Some("png") => {
+            let frame = last_frame(sim);
+            let size = image_size(std::slice::from_ref(&frame), scale, u32::MAX as usize)?;
+            let out = BufWriter::new(File::create(path)?);
+            write_png(out, &frame, size, scale, &options.palette)?;
+            Ok(1)
+        }
+        _ => Err(ExportError::Format(path.display().to_string())),
+    }
+}
+
+/// Size in pixels of an image holding any of the frames, checked against the largest width
+/// and height of the format.
+fn image_size(frames: &[Frame], scale: usize, max: usize) -> Result<(usize, usize), ExportError> {
+    let width = frames.iter().map(|frame| frame.width).max().unwrap_or(0);
+    let height = frames.iter().map(|frame| frame.height).max().unwrap_or(0);
+    let size = (width.max(1) * scale, height.max(1) * scale);
+    if size.0 > max || size.1 > max {
+        return Err(ExportError::TooLarge(size.0, size.1));
+    }
+    Ok(size)
+}
+
+/// The picture of a rendered frame, without its caption.
+struct Frame {
+    rows: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+}
+
+impl Frame {
+    fn new(render: &str) -> Self {
+        let rows: Vec<Vec<char>> = render
+            .lines()
+            .skip(1)
+            .map(|l| l.chars().collect())
+            .collect();
+        Self {
+            width: rows.iter().map(|row| row.len()).max().unwrap_or(0),
+            height: rows.len(),
+            rows,
+        }
+    }
+
+    /// Pixels of the frame scaled up on a canvas of `size`, the space around it is blank.
+    fn pixels<T: Copy>(&self, size: (usize, usize), scale: usize, f: impl Fn(char) -> T) -> Vec<T> {
+        let (width, height) = size;
+        let blank = f(' ');
+        let mut pixels = vec![blank; width * height];
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, &ch) in row.iter().enumerate() {
+                let value = f(ch);
+                for dy in 0..scale {
+                    let start = (y * scale + dy) * width + x * scale;
+                    pixels[start..start + scale].fill(value);
+                }
+            }
+        }
+        pixels
+    }
+}
+
+// The first frame, then every `skip + 1`-th one and the last one
+fn record(sim: &mut dyn Simulation, options: &Options) -> Vec<Frame> {
+    let mut frames = vec![Frame::new(&sim.render())];
+    let mut skipped = 0;
+    while frames.len() < options.max_frames.max(1) {
+        if !sim.step() {
+            frames.push(Frame::new(&sim.render()));
+            break;
+        }
+        if skipped == options.skip {
+            frames.push(Frame::new(&sim.render()));
+            skipped = 0;
+        } else {
+            skipped += 1;
+        }
+    }
+    frames
+}
+
+// The state the simulation ends in, or is in after `MAX_STEPS` steps
+fn last_frame(sim: &mut dyn Simulation) -> Frame {
+    let mut steps = 0;
+    while steps < MAX_STEPS && sim.step() {
+        steps += 1;
+    }
+    Frame::new(&sim.render())
+}
+
+// The colour table of a GIF: the blank colour first, then every distinct colour of the palette
+fn gif_colors(palette: &Palette) -> Result<Vec<Rgb>, ExportError> {
+    let mut colors: Vec<Rgb> = vec![palette.color(' '), palette.other];
+    for &color in palette.colors.values() {
+        if !colors.contains(&color) {
+            colors.push(color);
+        }
+    }
+    if colors.len() > GIF_COLORS {
+        return Err(ExportError::TooManyColors(colors.len()));
+    }
+    Ok(colors)
+}
+
+fn write_gif(
+    out: impl io::Write,
+    frames: &[Frame],
+    size: (usize, usize),
+    scale: usize,
+    colors: &[Rgb],
+    palette: &Palette,
+) -> Result<(), ExportError> {
+    let (width, height) = (size.0 as u16, size.1 as u16);
+    let index = |ch| colors.iter().position(|&c| c == palette.color(ch)).unwrap() as u8;
+
+    let mut encoder = gif::Encoder::new(out, width, height, colors.as_flattened())?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    for frame in frames {
+        let pixels = frame.pixels(size, scale, index);
+        let mut frame = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+        frame.delay = FRAME_DELAY;
+        encoder.write_frame(&frame)?;
+    }
+    Ok(())
+}
+
+fn write_png(
+    out: impl io::Write,
+    frame: &Frame,
+    size: (usize, usize),
+    scale: usize,
+    palette: &Palette,
+) -> Result<(), ExportError> {
+    let mut encoder = png::Encoder::new(out, size.0 as u32, size.1 as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let pixels = frame.pixels(size, scale, |ch| palette.color(ch));
+    encoder
+        .write_header()?
+        .write_image_data(pixels.as_flattened())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// A dot moving to the right of a 5 cells wide row.
+    struct Dot(usize);
+
+    impl Simulation for Dot {
+        fn step(&mut self) -> bool {
+            self.0 += 1;
+            self.0 < 4
+        }
+
+        fn render(&self) -> String {
+            let mut row = vec!['.'; 5];
+            row[self.0] = '#';
+            format!("at {}\n{}\n", self.0, row.into_iter().collect::<String>())
+        }
+    }
+
+    #[test]
+    fn parses_palettes() {
+        let palette: Palette = "o=c2b280,,=0000FF".parse().unwrap();
+        assert_eq!(palette.color('o'), [0xc2, 0xb2, 0x80]);
+        assert_eq!(palette.color(','), [0, 0, 0xff]);
+        assert_eq!(palette.color('#'), Palette::default().color('#'));
+
+        assert!("o=c2b28".parse::<Palette>().is_err());
+        assert!("o:c2b280".parse::<Palette>().is_err());
+        assert!("o=c2b2800".parse::<Palette>().is_err());
+        assert!("o=+2b280".parse::<Palette>().is_err());
+    }
+
+    #[test]
+    fn records_every_skip_plus_one_frame() {
+        let options = |skip, max_frames| Options {
+            skip,
+            max_frames,
+            ..Options::default()
+        };
+        let positions = |frames: Vec<Frame>| -> Vec<usize> {
+            frames
+                .iter()
+                .map(|frame| frame.rows[0].iter().position(|&ch| ch == '#').unwrap())
+                .collect()
+        };
+        assert_eq!(
+            positions(record(&mut Dot(0), &options(0, 100))),
+            [0, 1, 2, 3, 4]
+        );
+        assert_eq!(positions(record(&mut Dot(0), &options(2, 100))), [0, 3, 4]);
+        assert_eq!(positions(record(&mut Dot(0), &options(0, 2))), [0, 1]);
+    }
+
+    #[test]
+    fn scales_frames_up() {
+        let frame = Frame::new("caption\n#.\n.#");
+        let pixels = frame.pixels((5, 4), 2, |ch| ch);
+        let rows: Vec<String> = pixels.chunks(5).map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["##.. ", "##.. ", "..## ", "..## "]);
+    }
+
+    #[test]
+    fn writes_images() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let gif = dir.path().join("dot.gif");
+        assert_eq!(export(&mut Dot(0), &gif, &Options::default()).unwrap(), 5);
+        assert!(fs::read(&gif).unwrap().starts_with(b"GIF89a"));
+
+        // The PNG shows where the dot ends up, however few frames a GIF would take
+        let png = dir.path().join("dot.PNG");
+        let options = Options {
+            max_frames: 2,
+            ..Options::default()
+        };
+        assert_eq!(export(&mut Dot(0), &png, &options).unwrap(), 1);
+        assert!(fs::read(&png).unwrap().starts_with(b"\x89PNG"));
+        assert_eq!(
+            last_frame(&mut Dot(0)).rows,
+            [Vec::from_iter("....#".chars())]
+        );
+
+        let huge = dir.path().join("huge.gif");
+        let options = Options {
+            scale: 20_000,
+            ..Options::default()
+        };
+        assert!(matches!(
+            export(&mut Dot(0), &huge, &options),
+            Err(ExportError::TooLarge(100_000, 20_000))
+        ));
+        assert!(!huge.exists());
+
+        let mut options = Options::default();
+        let spec: Vec<String> = (0..300)
+            .map(|i| format!("{}={:06x}", char::from_u32(0x100 + i).unwrap(), i))
+            .collect();
+        options.palette.merge(&spec.join(",")).unwrap();
+        assert!(matches!(
+            export(&mut Dot(0), &gif, &options),
+            Err(ExportError::TooManyColors(_))
+        ));
+
+        let txt = dir.path().join("dot.txt");
+        assert!(matches!(
+            export(&mut Dot(0), &txt, &Options::default()),
+            Err(ExportError::Format(_))
+        ));
+    }
+}
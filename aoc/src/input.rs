@@ -0,0 +1,327 @@
+use serde::Deserialize;
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/eaniconer/adventofcode runner";
+
+/// Where a puzzle input is read from: a file, stdin (`-` on the command line) or a string
+/// already in memory, e.g. one embedded with `include_str!`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Source {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Path(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+                Ok(content)
+            }
+            Source::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Text(_) => write!(f, "<input>"),
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        })
+    }
+}
+
+/// Settings of `~/.config/aoc/config.toml`, each overridden by an environment variable.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// Session cookie of a logged in user (`AOC_SESSION`).
+    pub session: Option<String>,
+    /// Site to talk to (`AOC_BASE_URL`).
+    pub base_url: Option<String>,
+    /// Where downloaded inputs are kept (`AOC_CACHE_DIR`).
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, InputError> {
+        let mut config = match dirs::config_dir().map(|dir| dir.join("aoc/config.toml")) {
+            Some(path) if path.exists() => {
+                let content = fs::read_to_string(&path)?;
+                toml::from_str(&content).map_err(|err| {
+                    InputError::Config(format!("{}: {}", path.display(), err.message()))
+                })?
+            }
+            _ => Config::default(),
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        if let Some(cache_dir) = env::var_os("AOC_CACHE_DIR") {
+            config.cache_dir = Some(PathBuf::from(cache_dir));
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// No session token to authenticate with.
+    NoSession,
+    Config(String),
+    /// The site answered with an error status.
+    Status(u16),
+    Http(ureq::Error),
+    Io(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NoSession => write!(
+                f,
+                "no session token, set AOC_SESSION or 'session' in the config"
+            ),
+            InputError::Config(err) => write!(f, "invalid config {}", err),
+            InputError::Status(code) => write!(f, "server responded with status {}", code),
+            InputError::Http(err) => write!(f, "request failed: {}", err),
+            InputError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
+
+impl From<ureq::Error> for InputError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::StatusCode(code) => InputError::Status(code),
+            err => InputError::Http(err),
+        }
+    }
+}
+
+/// Downloads puzzle inputs, keeping every one of them on disk so it is fetched only once.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let cache_dir = config
+            .cache_dir
+            .or_else(|| dirs::cache_dir().map(|dir| dir.join("aoc")))
+            .unwrap_or_else(|| PathBuf::from(".aoc-cache"));
+
+        Self {
+            base_url: config
+                .base_url
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            session: config.session,
+            cache_dir,
+            agent: ureq::Agent::new_with_defaults(),
+        }
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn cache_path(&self, year: u32, day: u32) -> PathBuf {
+        self.cache_dir.join(format!("{}/day{}.txt", year, day))
+    }
+
+    /// The input, if it was downloaded before.
+    pub fn cached(&self, year: u32, day: u32) -> Option<String> {
+        fs::read_to_string(self.cache_path(year, day)).ok()
+    }
+
+    /// The input from the cache, downloading it on the first request.
+    pub fn input(&self, year: u32, day: u32) -> Result<String, InputError> {
+        if let Some(content) = self.cached(year, day) {
+            return Ok(content);
+        }
+
+        let content = self.get(&format!("/{}/day/{}/input", year, day))?;
+
+        let path = self.cache_path(year, day);
+        fs::create_dir_all(path.parent().unwrap())?;
+        let partial = path.with_extension("part");
+        fs::write(&partial, &content)?;
+        fs::rename(partial, path)?;
+
+        Ok(content)
+    }
+
+    fn get(&self, endpoint: &str) -> Result<String, InputError> {
+        let session = self.session.as_ref().ok_or(InputError::NoSession)?;
+        let body = self
+            .agent
+            .get(format!("{}{}", self.base_url, endpoint))
+            .header("Cookie", format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .call()?
+            .into_body()
+            .read_to_string()?;
+        Ok(body)
+    }
+
+    /// Posts an url-encoded form to `endpoint`, returning the response page.
+    pub(crate) fn post(&self, endpoint: &str, form: &[(&str, &str)]) -> Result<String, InputError> {
+        let session = self.session.as_ref().ok_or(InputError::NoSession)?;
+        let body = self
+            .agent
+            .post(format!("{}{}", self.base_url, endpoint))
+            .header("Cookie", format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())?
+            .into_body()
+            .read_to_string()?;
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+    use tiny_http::{Response, Server};
+
+    /// Serves the input of 2022 day 1 to the `good` session, counting the requests.
+    fn stub_server() -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let authorized = request
+                    .headers()
+                    .iter()
+                    .any(|h| h.field.equiv("Cookie") && h.value == "session=good");
+                let response = match (request.url(), authorized) {
+                    (_, false) => Response::from_string("Unauthorized").with_status_code(400),
+                    ("/2022/day/1/input", true) => Response::from_string("1000\n2000\n"),
+                    _ => Response::from_string("Not Found").with_status_code(404),
+                };
+                request.respond(response).unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn stub_client(url: &str, session: Option<&str>, cache: &tempfile::TempDir) -> Client {
+        Client::new(Config {
+            session: session.map(str::to_string),
+            base_url: Some(url.to_string()),
+            cache_dir: Some(cache.path().to_path_buf()),
+        })
+    }
+
+    #[test]
+    fn fetches_input_once() {
+        let (url, requests) = stub_server();
+        let cache = tempfile::tempdir().unwrap();
+        let client = stub_client(&url, Some("good"), &cache);
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(client.cached(2022, 1).as_deref(), Some("1000\n2000\n"));
+    }
+
+    #[test]
+    fn reports_server_errors() {
+        let (url, _) = stub_server();
+        let cache = tempfile::tempdir().unwrap();
+
+        let client = stub_client(&url, Some("good"), &cache);
+        assert!(matches!(
+            client.input(2022, 2),
+            Err(InputError::Status(404))
+        ));
+
+        let client = stub_client(&url, Some("bad"), &cache);
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(InputError::Status(400))
+        ));
+        assert!(client.cached(2022, 1).is_none());
+    }
+
+    #[test]
+    fn reads_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        fs::write(&path, "1\r\n2\r\n").unwrap();
+
+        let source: Source = path.to_str().unwrap().parse().unwrap();
+        assert_eq!(source.read().unwrap(), "1\r\n2\r\n");
+        assert_eq!("-".parse::<Source>().unwrap(), Source::Stdin);
+        assert_eq!(Source::Stdin.to_string(), "<stdin>");
+
+        let text = Source::Text("A Y\r\nB X\r\n".to_string());
+        assert_eq!(text.read().unwrap(), "A Y\r\nB X\r\n");
+        assert_eq!(text.to_string(), "<input>");
+        let day2 = crate::days::find(2022, 2).unwrap();
+        assert_eq!(day2.solve(&text.read().unwrap(), &[1]).unwrap(), ["9"]);
+    }
+
+    #[test]
+    fn requires_session() {
+        let (url, requests) = stub_server();
+        let cache = tempfile::tempdir().unwrap();
+        let client = stub_client(&url, None, &cache);
+
+        assert!(matches!(client.input(2022, 1), Err(InputError::NoSession)));
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,10 @@
+pub mod animation;
+pub mod answers;
+pub mod batch;
+pub mod days;
+pub mod export;
+pub mod input;
+pub mod logging;
+pub mod memory;
+pub mod scaffold;
+pub mod submit;
@@ -0,0 +1,22 @@
+use std::io::{self, IsTerminal};
+use tracing_subscriber::EnvFilter;
+
+/// Sends the events of the solvers to stderr. `RUST_LOG` selects what is shown; without it
+/// warnings are shown, and each `verbose` step adds a level: info, debug, then trace.
+pub fn init(verbose: u8) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        EnvFilter::new(match verbose {
+            0 => "warn",
+            1 => "info",
+            2 => "debug",
+            _ => "trace",
+        })
+    });
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
@@ -0,0 +1,925 @@
+use aoc::{
+    animation,
+    answers::{Ledger, Verdict},
+    batch,
+    days::{self, Parsed, SimulationFn},
+    export::{self, Palette},
+    input::{Client, Config, Source},
+    logging,
+    memory::{self, Usage},
+    scaffold,
+    submit::{self, Outcome},
+};
+use aoc2022_day2::{ByChoice, ByOutcome, Rules};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::{
+    io::{self, IsTerminal},
+    panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+use tracing::{debug, error};
+
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions runner")]
+struct Cli {
+    /// Show more of what the solvers do (-v info, -vv debug, -vvv trace); RUST_LOG takes
+    /// precedence
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver of the given day on a puzzle input
+    Run {
+        #[arg(long, required_unless_present = "all")]
+        year: Option<u32>,
+        #[arg(long, required_unless_present = "all")]
+        day: Option<u32>,
+        /// Part to run (both parts are run if omitted)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Puzzle input, `-` for stdin (defaults to YEAR/dayDAY/input.txt, downloaded if there
+        /// is none)
+        #[arg(long, conflicts_with = "all")]
+        input: Option<Source>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Run every registered day with a stored input (only of --year and --day if given)
+        #[arg(long)]
+        all: bool,
+        /// Number of days run at once with --all (defaults to the number of CPUs)
+        #[arg(long, requires = "all")]
+        jobs: Option<usize>,
+        /// Seconds a part may take with --all before it is reported as timed out
+        #[arg(long, requires = "all", default_value_t = 60)]
+        timeout: u64,
+        /// Animate the part (the first one if --part is omitted) in the terminal
+        #[arg(long, conflicts_with_all = ["all", "format"])]
+        visualize: bool,
+        /// Count the allocations of parsing and each part and report them with the answers
+        #[arg(long, conflicts_with = "visualize")]
+        profile_alloc: bool,
+    },
+    /// Render the animation of a part into an image: an animated GIF or its last frame as PNG
+    Export {
+        #[arg(long)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// Puzzle input, `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+        /// Image to write, `.gif` or `.png`
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Size of a cell in pixels
+        #[arg(long, default_value_t = 4)]
+        scale: u32,
+        /// Number of frames of a GIF left out after each exported one
+        #[arg(long, default_value_t = 0)]
+        skip: usize,
+        /// Frames of a GIF exported at most, the animation is cut short after them
+        #[arg(long, default_value_t = 1000)]
+        max_frames: usize,
+        /// Colours of cells by the character drawing them, e.g. `o=c2b280,#=404040`
+        #[arg(long)]
+        palette: Option<Palette>,
+    },
+    /// Score a strategy guide of 2022 day 2 under the rules of another hand game
+    HandGame {
+        /// Puzzle input, `-` for stdin (defaults to the one of 2022 day 2)
+        #[arg(long)]
+        input: Option<Source>,
+        /// Rules of the game as TOML, see `aoc2022_day2::RPSLS` (rock-paper-scissors if omitted)
+        #[arg(long)]
+        rules: Option<PathBuf>,
+        /// Score the guide under every reading of its second column as your choice instead,
+        /// round by round, with the best and the worst one
+        #[arg(long)]
+        what_if: bool,
+    },
+    /// Create the crate of a new day and register it with the runner
+    New {
+        #[arg(long)]
+        year: u32,
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+    /// Download the puzzle input of the given day into the cache
+    Fetch {
+        #[arg(long)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+    },
+    /// Submit an answer to the site and record the outcome in the ledger
+    Submit {
+        #[arg(long)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// Answer to submit (computed by the solver if omitted)
+        answer: Option<String>,
+        /// Puzzle input to compute the answer on, `-` for stdin
+        #[arg(long)]
+        input: Option<Source>,
+        /// Ledger of known answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Sleep until the site accepts answers again instead of giving up
+        #[arg(long)]
+        wait: bool,
+    },
+    /// Run the solvers on their stored inputs and check the answers against the ledger
+    Verify {
+        /// Only verify this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only verify this day
+        #[arg(long)]
+        day: Option<u32>,
+        /// Ledger of known answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// `DayN. TaskK: answer` lines
+    Text,
+    /// One JSON object per line and part
+    Json,
+}
+
+/// Outcome of a single part in `--format json`.
+#[derive(Serialize)]
+struct PartReport {
+    year: u32,
+    day: u32,
+    part: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    /// Time spent on the part, parsing excluded.
+    elapsed_ms: f64,
+    /// Time spent on parsing the input.
+    parse_ms: f64,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Memory allocated by the part, with --profile-alloc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alloc: Option<Usage>,
+    /// Memory allocated by parsing the input, with --profile-alloc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_alloc: Option<Usage>,
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Ok,
+    Error,
+    Timeout,
+    Skipped,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Timeout => "timeout",
+            Status::Skipped => "skipped",
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logging::init(cli.verbose);
+    if let Command::Run {
+        profile_alloc: true,
+        ..
+    } = cli.command
+    {
+        memory::enable();
+    }
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            format,
+            all: true,
+            jobs,
+            timeout,
+            ..
+        } => run_all(year, day, part, format, jobs, Duration::from_secs(timeout)),
+        Command::Run {
+            year: Some(year),
+            day: Some(day),
+            part,
+            input,
+            visualize: true,
+            ..
+        } => visualize(year, day, part.unwrap_or(1), input),
+        Command::Run {
+            year: Some(year),
+            day: Some(day),
+            part,
+            input,
+            format,
+            ..
+        } => run(year, day, part, input, format),
+        Command::Run { .. } => unreachable!("--year and --day are required without --all"),
+        Command::Export {
+            year,
+            day,
+            part,
+            input,
+            output,
+            scale,
+            skip,
+            max_frames,
+            palette,
+        } => {
+            let options = export::Options {
+                scale,
+                skip,
+                max_frames,
+                palette: palette.unwrap_or_default(),
+            };
+            export_animation(year, day, part, input, &output, &options)
+        }
+        Command::HandGame {
+            input,
+            rules,
+            what_if,
+        } => hand_game(input, rules.as_deref(), what_if),
+        Command::New { year, day } => new_day(year, day),
+        Command::Fetch { year, day } => fetch(year, day),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            input,
+            answers,
+            wait,
+        } => submit_answer(year, day, part, answer, input, &answers, wait),
+        Command::Verify { year, day, answers } => verify(year, day, &answers),
+    }
+}
+
+// Finds the simulation of the day and parses its input, errors are logged
+fn load_simulation(year: u32, day: u32, input: Option<Source>) -> Option<(SimulationFn, Parsed)> {
+    let Some(solution) = days::find(year, day) else {
+        error!("No solution registered for {} day {}", year, day);
+        return None;
+    };
+    let Some(simulation) = solution.simulation else {
+        error!("{} day {} has no visualization", year, day);
+        return None;
+    };
+
+    let (content, input) = match read_input(year, day, input, true) {
+        Ok(input) => input,
+        Err(err) => {
+            error!("{}", err);
+            return None;
+        }
+    };
+    match solution.parse_input(&content) {
+        Ok(parsed) => Some((simulation, parsed)),
+        Err(err) => {
+            eprintln!("{}", err.render(&input.to_string()));
+            error!("{}:{}", input, err);
+            None
+        }
+    }
+}
+
+fn visualize(year: u32, day: u32, part: u32, input: Option<Source>) -> ExitCode {
+    if !io::stdout().is_terminal() {
+        error!("--visualize needs a terminal");
+        return ExitCode::FAILURE;
+    }
+    let Some((simulation, parsed)) = load_simulation(year, day, input) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut sim = simulation(parsed.as_ref(), part);
+    match animation::play(sim.as_mut()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            error!("Cannot animate {} day {}: {}", year, day, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn export_animation(
+    year: u32,
+    day: u32,
+    part: u32,
+    input: Option<Source>,
+    output: &Path,
+    options: &export::Options,
+) -> ExitCode {
+    let Some((simulation, parsed)) = load_simulation(year, day, input) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut sim = simulation(parsed.as_ref(), part);
+    match export::export(sim.as_mut(), output, options) {
+        Ok(frames) => {
+            println!("{} frame(s) written to {}", frames, output.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            error!("Cannot export {} day {}: {}", year, day, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(year: u32, day: u32, part: Option<u32>, input: Option<Source>, format: Format) -> ExitCode {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let report_error = |err: String| {
+        error!("{}", err);
+        if let Format::Json = format {
+            for &part in &parts {
+                print_report(&PartReport {
+                    year,
+                    day,
+                    part,
+                    answer: None,
+                    elapsed_ms: 0.0,
+                    parse_ms: 0.0,
+                    status: Status::Error,
+                    error: Some(err.clone()),
+                    alloc: None,
+                    parse_alloc: None,
+                });
+            }
+        }
+        ExitCode::FAILURE
+    };
+
+    let Some(solution) = days::find(year, day) else {
+        return report_error(format!("No solution registered for {} day {}", year, day));
+    };
+
+    let (content, input) = match read_input(year, day, input, true) {
+        Ok(input) => input,
+        Err(err) => return report_error(err),
+    };
+
+    let _span = solution.span().entered();
+    let start = Instant::now();
+    let (parsed, parse_usage) = memory::measure(|| solution.parse_input(&content));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}", err.render(&input.to_string()));
+            return report_error(format!("{}:{}", input, err));
+        }
+    };
+    let parse_time = start.elapsed();
+    let profiled = |usage| memory::enabled().then_some(usage);
+
+    if let (Format::Text, Some(usage)) = (format, profiled(parse_usage)) {
+        println!("Day{}. Parse memory: {}", day, usage);
+    }
+    // A part that panics is reported like a failed one, the others are still run
+    panic::set_hook(Box::new(|info| debug!("{}", info)));
+    let mut failed = false;
+    for part in parts.iter().copied() {
+        let start = Instant::now();
+        let (answer, usage) =
+            memory::measure(|| batch::catch_panic(|| solution.run_part(&parsed, part)));
+        let elapsed = start.elapsed();
+        let answer = answer.map_err(|message| format!("panicked: {}", message));
+        if let Err(err) = &answer {
+            error!("Day{}. Task{} {}", day, part, err);
+            failed = true;
+        }
+
+        match (format, answer) {
+            (Format::Text, Ok(answer)) => {
+                print_answer(day, part, &answer);
+                if let Some(usage) = profiled(usage) {
+                    println!("Day{}. Task{} memory: {}", day, part, usage);
+                }
+            }
+            (Format::Text, Err(_)) => {}
+            (Format::Json, answer) => print_report(&PartReport {
+                year,
+                day,
+                part,
+                status: if answer.is_ok() {
+                    Status::Ok
+                } else {
+                    Status::Error
+                },
+                answer: answer.as_ref().ok().cloned(),
+                elapsed_ms: millis(elapsed),
+                parse_ms: millis(parse_time),
+                error: answer.err(),
+                alloc: profiled(usage),
+                parse_alloc: profiled(parse_usage),
+            }),
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_all(
+    year: Option<u32>,
+    day: Option<u32>,
+    part: Option<u32>,
+    format: Format,
+    jobs: Option<usize>,
+    timeout: Duration,
+) -> ExitCode {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let days = days::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|year| d.year == year))
+        .filter(|d| day.is_none_or(|day| d.day == day));
+
+    let mut tasks = Vec::new();
+    let mut reports = Vec::new();
+    for solution in days {
+        match read_input(solution.year, solution.day, None, false) {
+            Ok((content, _)) => {
+                let content: Arc<str> = Arc::from(content);
+                for &part in &parts {
+                    tasks.push(batch::Task {
+                        day: solution,
+                        part,
+                        content: Arc::clone(&content),
+                    });
+                }
+            }
+            Err(err) => {
+                for &part in &parts {
+                    reports.push(PartReport {
+                        year: solution.year,
+                        day: solution.day,
+                        part,
+                        answer: None,
+                        elapsed_ms: 0.0,
+                        parse_ms: 0.0,
+                        status: Status::Skipped,
+                        error: Some(err.clone()),
+                        alloc: None,
+                        parse_alloc: None,
+                    });
+                }
+            }
+        }
+    }
+
+    // Panics are reported with the task they happened in
+    panic::set_hook(Box::new(|info| debug!("{}", info)));
+
+    let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+    reports.extend(batch::run(tasks, jobs, timeout).into_iter().map(|report| {
+        let (answer, status, error) = match report.outcome {
+            batch::Outcome::Answer(answer) => (Some(answer), Status::Ok, None),
+            batch::Outcome::ParseError(err) => (None, Status::Error, Some(err)),
+            batch::Outcome::Panic(message) => {
+                (None, Status::Error, Some(format!("panicked: {}", message)))
+            }
+            batch::Outcome::TimedOut => (
+                None,
+                Status::Timeout,
+                Some(format!("timed out after {:?}", timeout)),
+            ),
+        };
+        PartReport {
+            year: report.year,
+            day: report.day,
+            part: report.part,
+            answer,
+            elapsed_ms: millis(report.part_time),
+            parse_ms: millis(report.parse_time),
+            status,
+            error,
+            alloc: memory::enabled().then_some(report.part_usage),
+            parse_alloc: memory::enabled().then_some(report.parse_usage),
+        }
+    }));
+    reports.sort_by_key(|r| (r.year, r.day, r.part));
+
+    match format {
+        Format::Text => print_table(&reports),
+        Format::Json => reports.iter().for_each(print_report),
+    }
+
+    let failed = reports
+        .iter()
+        .any(|r| matches!(r.status, Status::Error | Status::Timeout));
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_table(reports: &[PartReport]) {
+    // Allocations and peak memory of a phase, with --profile-alloc
+    let profiled = reports.iter().any(|r| r.alloc.is_some());
+    let memory = |usage: Option<Usage>| match (profiled, usage) {
+        (false, _) => String::new(),
+        (true, None) => format!(" {:>24}", ""),
+        (true, Some(u)) => format!(
+            " {:>24}",
+            format!("{} / {}", u.allocations, memory::format_bytes(u.peak_bytes))
+        ),
+    };
+    let memory_header = match profiled {
+        true => format!(" {:>24} {:>24}", "parse allocs / peak", "allocs / peak"),
+        false => String::new(),
+    };
+
+    println!(
+        "{:<12} {:<4} {:<8} {:>10} {:>10}{}  answer",
+        "day", "part", "status", "parse", "time", memory_header
+    );
+    let mut multiline = Vec::new();
+    for r in reports {
+        let answer = match (&r.answer, &r.error) {
+            (Some(answer), _) if answer.contains('\n') => {
+                multiline.push(r);
+                "(below)"
+            }
+            (Some(answer), _) => answer,
+            (None, Some(error)) => error,
+            (None, None) => "",
+        };
+        println!(
+            "{:<12} {:<4} {:<8} {:>10} {:>10}{}{}  {}",
+            format!("{}-day{}", r.year, r.day),
+            r.part,
+            r.status.label(),
+            format!("{:.1} ms", r.parse_ms),
+            format!("{:.1} ms", r.elapsed_ms),
+            memory(r.parse_alloc),
+            memory(r.alloc),
+            answer
+        );
+    }
+    for r in multiline {
+        println!(
+            "\n{} day {} part {}:\n{}",
+            r.year,
+            r.day,
+            r.part,
+            r.answer.as_deref().unwrap_or_default().trim_end()
+        );
+    }
+
+    let count = |status: fn(&Status) -> bool| reports.iter().filter(|r| status(&r.status)).count();
+    println!(
+        "\n{} ok, {} failed, {} timed out, {} skipped",
+        count(|s| matches!(s, Status::Ok)),
+        count(|s| matches!(s, Status::Error)),
+        count(|s| matches!(s, Status::Timeout)),
+        count(|s| matches!(s, Status::Skipped)),
+    );
+}
+
+fn submit_answer(
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: Option<String>,
+    input: Option<Source>,
+    answers: &Path,
+    wait: bool,
+) -> ExitCode {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let Some(solution) = days::find(year, day) else {
+                eprintln!("No solution registered for {} day {}", year, day);
+                return ExitCode::FAILURE;
+            };
+            let (content, input) = match read_input(year, day, input, true) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            match solution.solve(&content, &[part]) {
+                Ok(mut answers) => answers.remove(0),
+                Err(err) => {
+                    eprintln!("{}", err.render(&input.to_string()));
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+    print_answer(day, part, &answer);
+
+    let mut ledger = match Ledger::load(answers) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            eprintln!("Cannot load '{}': {}", answers.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = match Config::load() {
+        Ok(config) => Client::new(config),
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match submit::submit(&client, &mut ledger, (year, day, part), &answer, wait) {
+        Ok(outcome) => {
+            println!("{}", outcome);
+            if let Err(err) = ledger.save(answers) {
+                eprintln!("Cannot save '{}': {}", answers.display(), err);
+                return ExitCode::FAILURE;
+            }
+            if outcome == Outcome::Correct {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn verify(year: Option<u32>, day: Option<u32>, answers: &Path) -> ExitCode {
+    let ledger = match Ledger::load(answers) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            eprintln!("Cannot load '{}': {}", answers.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut failed, mut new, mut skipped) = (0, 0, 0, 0);
+    // Panics are reported as failures of the part they happened in
+    panic::set_hook(Box::new(|info| debug!("{}", info)));
+
+    let days = days::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|year| d.year == year))
+        .filter(|d| day.is_none_or(|day| d.day == day));
+
+    for solution in days {
+        let (content, input) = match read_input(solution.year, solution.day, None, false) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("SKIP {} day {}: {}", solution.year, solution.day, err);
+                skipped += 1;
+                continue;
+            }
+        };
+
+        let _span = solution.span().entered();
+        let parsed = match batch::catch_panic(|| solution.parse_input(&content)) {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(err)) => {
+                println!(
+                    "FAIL {} day {}: cannot parse input",
+                    solution.year, solution.day
+                );
+                eprintln!("{}", err.render(&input.to_string()));
+                failed += 1;
+                continue;
+            }
+            Err(message) => {
+                println!(
+                    "FAIL {} day {}: parsing panicked: {}",
+                    solution.year, solution.day, message
+                );
+                failed += 1;
+                continue;
+            }
+        };
+
+        for part in [1, 2] {
+            let label = format!("{} day {} part {}", solution.year, solution.day, part);
+            let answer = match batch::catch_panic(|| solution.run_part(&parsed, part)) {
+                Ok(answer) => answer,
+                Err(message) => {
+                    println!("FAIL {}: panicked: {}", label, message);
+                    failed += 1;
+                    continue;
+                }
+            };
+            match ledger.check(solution.year, solution.day, part, &answer) {
+                Verdict::Pass => {
+                    println!("PASS {}", label);
+                    passed += 1;
+                }
+                Verdict::Fail(reason) => {
+                    println!("FAIL {}\n{}", label, reason);
+                    failed += 1;
+                }
+                Verdict::New => {
+                    if answer.contains('\n') {
+                        println!("NEW  {}:\n{}", label, answer.trim_end());
+                    } else {
+                        println!("NEW  {}: {}", label, answer);
+                    }
+                    new += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} new, {} skipped",
+        passed, failed, new, skipped
+    );
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn hand_game(input: Option<Source>, rules: Option<&Path>, what_if: bool) -> ExitCode {
+    let rules = match rules {
+        Some(path) => {
+            let config = match std::fs::read_to_string(path) {
+                Ok(config) => config,
+                Err(err) => {
+                    error!("Cannot read '{}': {}", path.display(), err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            match Rules::from_toml(&config) {
+                Ok(rules) => rules,
+                Err(err) => {
+                    error!("{}: {}", path.display(), err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        None => Rules::default(),
+    };
+
+    let (content, input) = match read_input(2022, 2, input, true) {
+        Ok(input) => input,
+        Err(err) => {
+            error!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let guide = match rules.parse_guide(&content) {
+        Ok(guide) => guide,
+        Err(err) => {
+            eprintln!("{}", err.render(&input.to_string()));
+            error!("{}:{}", input, err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if what_if {
+        println!("{}", rules.what_if(&guide));
+        return ExitCode::SUCCESS;
+    }
+    println!("Day2. Task1: {}", rules.score(&guide, &ByChoice));
+    println!("Day2. Task2: {}", rules.score(&guide, &ByOutcome));
+    ExitCode::SUCCESS
+}
+
+fn new_day(year: u32, day: u32) -> ExitCode {
+    if !Path::new("aoc/src/days.rs").exists() {
+        eprintln!("Run from the root of the workspace");
+        return ExitCode::FAILURE;
+    }
+
+    match scaffold::new_day(Path::new(""), year, day) {
+        Ok(files) => {
+            for file in files {
+                println!("{}", file.display());
+            }
+            println!(
+                "Put the input into {} or download it with `aoc fetch`",
+                default_input_path(year, day).display()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Cannot create {} day {}: {}", year, day, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(year: u32, day: u32) -> ExitCode {
+    let client = match Config::load() {
+        Ok(config) => Client::new(config),
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match client.input(year, day) {
+        Ok(_) => {
+            println!("{}", client.cache_path(year, day).display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Cannot fetch the input of {} day {}: {}", year, day, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Content of the puzzle input and where it was read from: the `input` source if given,
+/// otherwise `YEAR/dayDAY/input.txt` or the cached download, fetched first if `download`.
+fn read_input(
+    year: u32,
+    day: u32,
+    input: Option<Source>,
+    download: bool,
+) -> Result<(String, Source), String> {
+    if let Some(input) = input {
+        return match input.read() {
+            Ok(content) => Ok((content, input)),
+            Err(err) => Err(format!("Cannot read '{}': {}", input, err)),
+        };
+    }
+
+    let local = Source::Path(default_input_path(year, day));
+    if let Ok(content) = local.read() {
+        return Ok((content, local));
+    }
+
+    let client = Client::new(Config::load().map_err(|err| err.to_string())?);
+    let content = if download {
+        client
+            .input(year, day)
+            .map_err(|err| format!("Cannot fetch the input of {} day {}: {}", year, day, err))?
+    } else {
+        client.cached(year, day).ok_or("no input")?
+    };
+    Ok((content, Source::Path(client.cache_path(year, day))))
+}
+
+fn default_input_path(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(format!("{}/day{}/input.txt", year, day))
+}
+
+fn print_report(report: &PartReport) {
+    println!("{}", serde_json::to_string(report).unwrap());
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn print_answer(day: u32, part: u32, answer: &str) {
+    if answer.contains('\n') {
+        println!("Day{}. Task{}:\n{}", day, part, answer.trim_end());
+    } else {
+        println!("Day{}. Task{}: {}", day, part, answer);
+    }
+}
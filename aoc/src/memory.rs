@@ -0,0 +1,205 @@
+use serde::Serialize;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters::ZERO) };
+}
+
+/// Allocations made by the current thread so far.
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    allocated: u64,
+    /// Bytes allocated and not freed yet, negative if the thread frees memory of others
+    live: i64,
+    peak: i64,
+}
+
+impl Counters {
+    const ZERO: Self = Self {
+        allocations: 0,
+        allocated: 0,
+        live: 0,
+        peak: 0,
+    };
+}
+
+fn record(allocation: bool, allocated: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // Nothing is counted while the thread is being torn down
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        c.allocations += allocation as u64;
+        c.allocated += allocated as u64;
+        c.live += allocated as i64 - freed as i64;
+        c.peak = c.peak.max(c.live);
+        counters.set(c);
+    });
+}
+
+/// The system allocator, counting the allocations of each thread once [`enable`]d.
+///
+/// Only counts when installed with `#[global_allocator]`.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(true, layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(true, layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(false, 0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        // One allocation of the bytes it grows by, the ones kept were counted already
+        if !new_ptr.is_null() {
+            let old_size = layout.size();
+            record(
+                true,
+                new_size.saturating_sub(old_size),
+                old_size.saturating_sub(new_size),
+            );
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting allocations.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Memory allocated by a piece of code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct Usage {
+    /// Number of allocations, reallocations included.
+    pub allocations: u64,
+    /// Bytes allocated in total, a reallocation counting the bytes it grows by.
+    pub bytes: u64,
+    /// Most bytes held at once.
+    pub peak_bytes: u64,
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} in total, {} at peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Runs `f` on the current thread and measures the memory it allocates, all zeros unless
+/// counting is enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let before = COUNTERS.with(|counters| {
+        let mut c = counters.get();
+        c.peak = c.live;
+        counters.set(c);
+        c
+    });
+    let result = f();
+    let after = COUNTERS.with(Cell::get);
+
+    let usage = Usage {
+        allocations: after.allocations - before.allocations,
+        bytes: after.allocated - before.allocated,
+        peak_bytes: (after.peak - before.live).max(0) as u64,
+    };
+    (result, usage)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn measures_allocations() {
+        enable();
+        let (_, usage) = measure(|| {
+            let small = black_box(vec![0u8; 1000]);
+            drop(small);
+            black_box(vec![0u8; 4000])
+        });
+        assert_eq!(usage.allocations, 2);
+        assert_eq!(usage.bytes, 5000);
+        assert_eq!(usage.peak_bytes, 4000);
+
+        let (_, usage) = measure(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(1);
+            v.extend(0..100);
+            black_box(v.len())
+        });
+        assert!(usage.allocations >= 2);
+        assert!(usage.peak_bytes >= 800);
+
+        // Growing a buffer only counts the bytes added
+        let mut v: Vec<u8> = black_box(Vec::with_capacity(1000));
+        let (_, usage) = measure(|| {
+            v.reserve_exact(3000);
+            black_box(v.capacity())
+        });
+        assert_eq!(usage.allocations, 1);
+        assert_eq!(usage.bytes, 2000);
+        assert_eq!(usage.peak_bytes, 2000);
+        let (_, usage) = measure(|| v.shrink_to(10));
+        assert_eq!((usage.bytes, usage.peak_bytes), (0, 0));
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML: &str = r#"[package]
+name = "aoc{year}-day{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-core = { path = "../../aoc-core" }
+"#;
+
+const LIB_RS: &str = r#"use aoc_core::{parse_lines, ParseError, Solution};
+use std::fmt::Display;
+
+fn task_1(_lines: &[String]) -> usize {
+    todo!()
+}
+
+fn task_2(_lines: &[String]) -> usize {
+    todo!()
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(content, |line| Ok(line.to_string()))
+    }
+
+    fn part1(lines: &Self::Input) -> impl Display {
+        task_1(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> impl Display {
+        task_2(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "the example is not filled in yet"]
+    fn part1_example() {
+        let input = Day{day}::parse(EXAMPLE).unwrap();
+        assert_eq!(Day{day}::part1(&input).to_string(), "");
+    }
+
+    #[test]
+    #[ignore = "the example is not filled in yet"]
+    fn part2_example() {
+        let input = Day{day}::parse(EXAMPLE).unwrap();
+        assert_eq!(Day{day}::part2(&input).to_string(), "");
+    }
+}
+"#;
+
+/// Creates the `YEAR/dayDAY` crate in the workspace at `root` and registers it with the
+/// runner. Returns the files written.
+pub fn new_day(root: &Path, year: u32, day: u32) -> io::Result<Vec<PathBuf>> {
+    let dir = root.join(format!("{}/day{}", year, day));
+    for file in ["Cargo.toml", "src"] {
+        if dir.join(file).exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", dir.join(file).display()),
+            ));
+        }
+    }
+
+    let fill = |template: &str| {
+        template
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+    };
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), fill(CARGO_TOML))?;
+    fs::write(dir.join("src/lib.rs"), fill(LIB_RS))?;
+
+    register(
+        &root.join("Cargo.toml"),
+        |line| {
+            let (y, d) = line.trim().strip_prefix('"')?.split_once("/day")?;
+            Some((y.parse().ok()?, d.strip_suffix("\",")?.parse().ok()?))
+        },
+        (year, day),
+        format!("    \"{}/day{}\",", year, day),
+    )?;
+    register(
+        &root.join("aoc/Cargo.toml"),
+        |line| {
+            let (y, d) = line.strip_prefix("aoc")?.split_once("-day")?;
+            Some((y.parse().ok()?, d.split_once(' ')?.0.parse().ok()?))
+        },
+        (year, day),
+        format!(
+            "aoc{}-day{} = {{ path = \"../{}/day{}\" }}",
+            year, day, year, day
+        ),
+    )?;
+    register(
+        &root.join("aoc/src/days.rs"),
+        |line| {
+            let mut args = line.trim().strip_prefix("day!(")?.split(", ");
+            Some((args.next()?.parse().ok()?, args.next()?.parse().ok()?))
+        },
+        (year, day),
+        format!(
+            "    day!({}, {}, aoc{}_day{}::Day{}),",
+            year, day, year, day, day
+        ),
+    )?;
+
+    Ok(vec![
+        dir.join("Cargo.toml"),
+        dir.join("src/lib.rs"),
+        root.join("Cargo.toml"),
+        root.join("aoc/Cargo.toml"),
+        root.join("aoc/src/days.rs"),
+    ])
+}
+
+/// Inserts `entry` into the list of days in `path`, keeping it ordered by year and day.
+/// `key` recognizes the lines of the list.
+fn register<F>(path: &Path, key: F, new_key: (u32, u32), entry: String) -> io::Result<()>
+where
+    F: Fn(&str) -> Option<(u32, u32)>,
+{
+    let content = fs::read_to_string(path)?;
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let keyed: Vec<(usize, (u32, u32))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, key(line)?)))
+        .collect();
+    if keyed.iter().any(|&(_, k)| k == new_key) {
+        return Ok(());
+    }
+
+    let idx = match keyed.iter().rev().find(|&&(_, k)| k < new_key) {
+        Some(&(idx, _)) => idx + 1,
+        None => match keyed.first() {
+            Some(&(idx, _)) => idx,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("no days are listed in {}", path.display()),
+                ))
+            }
+        },
+    };
+    lines.insert(idx, &entry);
+
+    fs::write(path, lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("aoc/src")).unwrap();
+        fs::write(
+            root.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2022/day2\",\n    \"2022/day25\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.path().join("aoc/Cargo.toml"),
+            "[dependencies]\nclap = \"4\"\naoc2022-day2 = { path = \"../2022/day2\" }\naoc2022-day25 = { path = \"../2022/day25\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.path().join("aoc/src/days.rs"),
+            "pub static DAYS: &[Day] = &[\n    day!(2022, 2, aoc2022_day2::Day2),\n    day!(2022, 25, aoc2022_day25::Day25),\n];\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn registers_days_in_order() {
+        let root = workspace();
+        new_day(root.path(), 2022, 10).unwrap();
+        new_day(root.path(), 2023, 1).unwrap();
+
+        let read = |path: &str| fs::read_to_string(root.path().join(path)).unwrap();
+        assert_eq!(
+            read("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2022/day2\",\n    \"2022/day10\",\n    \"2022/day25\",\n    \"2023/day1\",\n]\n"
+        );
+        assert!(read("aoc/Cargo.toml").ends_with(
+            "aoc2022-day10 = { path = \"../2022/day10\" }\naoc2022-day25 = { path = \"../2022/day25\" }\naoc2023-day1 = { path = \"../2023/day1\" }\n"
+        ));
+        assert!(read("aoc/src/days.rs").contains(
+            "    day!(2022, 10, aoc2022_day10::Day10),\n    day!(2022, 25, aoc2022_day25::Day25),\n    day!(2023, 1, aoc2023_day1::Day1),\n];"
+        ));
+        assert!(read("2023/day1/Cargo.toml").contains("name = \"aoc2023-day1\""));
+        assert!(read("2023/day1/src/lib.rs").contains("impl Solution for Day1 {"));
+    }
+
+    #[test]
+    fn keeps_existing_crates() {
+        let root = workspace();
+        new_day(root.path(), 2022, 10).unwrap();
+        assert_eq!(
+            new_day(root.path(), 2022, 10).unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+    }
+}
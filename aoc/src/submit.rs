@@ -0,0 +1,368 @@
+use crate::{
+    answers::{Ledger, Verdict},
+    input::{Client, InputError},
+};
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, the next one is accepted after the wait.
+    RateLimited(Duration),
+    /// The part is already solved, the answer was not checked.
+    AlreadySolved,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer"),
+            Outcome::Wrong => write!(f, "That's not the right answer"),
+            Outcome::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            Outcome::TooLow => write!(f, "That's not the right answer, it is too low"),
+            Outcome::RateLimited(wait) => {
+                write!(f, "Answered too recently, wait {}s", wait.as_secs())
+            }
+            Outcome::AlreadySolved => write!(f, "The part is already solved"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The ledger tells the answer is wrong.
+    KnownWrong(String),
+    /// The ledger has an accepted answer for the part.
+    AlreadyAccepted(String),
+    /// The site would not accept an answer before the wait is over.
+    Throttled(Duration),
+    UnexpectedResponse,
+    Client(InputError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::KnownWrong(reason) => write!(f, "not submitted, {}", reason),
+            SubmitError::AlreadyAccepted(accepted) => {
+                write!(f, "not submitted, '{}' was already accepted", accepted)
+            }
+            SubmitError::Throttled(wait) => write!(
+                f,
+                "not submitted, wait {}s before the next answer (or pass --wait)",
+                wait.as_secs()
+            ),
+            SubmitError::UnexpectedResponse => write!(f, "unexpected response page"),
+            SubmitError::Client(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+impl From<InputError> for SubmitError {
+    fn from(err: InputError) -> Self {
+        SubmitError::Client(err)
+    }
+}
+
+/// Submits `answer` unless the ledger already knows it is wrong, and records the outcome
+/// in the ledger. With `wait`, sleeps through the rate limit instead of giving up.
+pub fn submit(
+    client: &Client,
+    ledger: &mut Ledger,
+    (year, day, part): (u32, u32, u32),
+    answer: &str,
+    wait: bool,
+) -> Result<Outcome, SubmitError> {
+    if let Some(accepted) = ledger.get(year, day, part).and_then(|a| a.accepted.clone()) {
+        return Err(SubmitError::AlreadyAccepted(accepted));
+    }
+    if let Verdict::Fail(reason) = ledger.check(year, day, part, answer) {
+        return Err(SubmitError::KnownWrong(reason));
+    }
+
+    loop {
+        if let Some(remaining) = throttled_for(client) {
+            if !wait {
+                return Err(SubmitError::Throttled(remaining));
+            }
+            thread::sleep(remaining);
+        }
+
+        let page = client.post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        let outcome = parse_outcome(&page).ok_or(SubmitError::UnexpectedResponse)?;
+        // A rate limited answer is throttled even when the page does not tell for how long,
+        // otherwise `wait` would post it again straight away
+        let wait_time = match outcome {
+            Outcome::RateLimited(wait_time) => Some(wait_time),
+            _ => wait_time(&page),
+        };
+        if let Some(wait_time) = wait_time {
+            throttle(client, wait_time);
+        }
+
+        let answers = ledger.get_mut(year, day, part);
+        match outcome {
+            Outcome::Correct => answers.accept(answer),
+            Outcome::Wrong => answers.reject(answer),
+            Outcome::TooHigh => answers.reject_too_high(answer),
+            Outcome::TooLow => answers.reject_too_low(answer),
+            Outcome::RateLimited(_) if wait => continue,
+            Outcome::RateLimited(_) | Outcome::AlreadySolved => {}
+        }
+        return Ok(outcome);
+    }
+}
+
+/// Reads the outcome from the page the site responds with.
+pub fn parse_outcome(page: &str) -> Option<Outcome> {
+    if page.contains("That's the right answer") {
+        Some(Outcome::Correct)
+    } else if page.contains("You gave an answer too recently") {
+        Some(Outcome::RateLimited(
+            wait_time(page).unwrap_or(Duration::from_secs(60)),
+        ))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Some(Outcome::AlreadySolved)
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Some(Outcome::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Outcome::TooLow)
+        } else {
+            Some(Outcome::Wrong)
+        }
+    } else {
+        None
+    }
+}
+
+/// Time to wait before the next answer: "You have 1m 23s left to wait" after a too
+/// early answer or "please wait 5 minutes before trying again" after a wrong one.
+fn wait_time(page: &str) -> Option<Duration> {
+    if let Some((_, rest)) = page.split_once("You have ") {
+        if let Some((left, _)) = rest.split_once(" left to wait") {
+            let secs = left
+                .split_whitespace()
+                .map(parse_span)
+                .sum::<Option<u64>>()?;
+            return Some(Duration::from_secs(secs));
+        }
+    }
+
+    let page = page.to_lowercase();
+    let (_, rest) = page.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let n = match words.next()? {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        n => n.parse().ok()?,
+    };
+    let unit = match words.next()? {
+        unit if unit.starts_with("second") => 1,
+        unit if unit.starts_with("minute") => 60,
+        unit if unit.starts_with("hour") => 60 * 60,
+        _ => return None,
+    };
+    Some(Duration::from_secs(n * unit))
+}
+
+/// Seconds in a span like `1h`, `2m` or `30s`.
+fn parse_span(span: &str) -> Option<u64> {
+    let unit = span.chars().last()?;
+    let n: u64 = span[..span.len() - unit.len_utf8()].parse().ok()?;
+    match unit {
+        'h' => Some(n * 60 * 60),
+        'm' => Some(n * 60),
+        's' => Some(n),
+        _ => None,
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_secs())
+}
+
+/// Remaining wait recorded by a previous submission, if any.
+fn throttled_for(client: &Client) -> Option<Duration> {
+    let content = fs::read_to_string(client.cache_dir().join("submit-after")).ok()?;
+    let deadline: u64 = content.trim().parse().ok()?;
+    let now = unix_now();
+    (deadline > now).then(|| Duration::from_secs(deadline - now))
+}
+
+fn throttle(client: &Client, wait: Duration) {
+    let deadline = unix_now() + wait.as_secs();
+    // Losing the deadline only means the site tells about the wait again.
+    let _ = fs::create_dir_all(client.cache_dir()).and_then(|_| {
+        fs::write(
+            client.cache_dir().join("submit-after"),
+            deadline.to_string(),
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Config;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use tiny_http::{Response, Server};
+
+    const CORRECT: &str = "<article><p>That's the right answer! You are one gold star closer to saving your vacation.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+    const SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(parse_outcome(CORRECT), Some(Outcome::Correct));
+        assert_eq!(parse_outcome(TOO_HIGH), Some(Outcome::TooHigh));
+        assert_eq!(
+            parse_outcome(&TOO_HIGH.replace("too high", "too low")),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            parse_outcome("<p>That's not the right answer.  Please wait 5 minutes.</p>"),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            parse_outcome(TOO_RECENT),
+            Some(Outcome::RateLimited(Duration::from_secs(83)))
+        );
+        assert_eq!(parse_outcome(SOLVED), Some(Outcome::AlreadySolved));
+        assert_eq!(parse_outcome("<html>Puzzle inputs differ</html>"), None);
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(wait_time(TOO_HIGH), Some(Duration::from_secs(60)));
+        assert_eq!(
+            wait_time("Please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            wait_time("You have 34s left to wait."),
+            Some(Duration::from_secs(34))
+        );
+        assert_eq!(wait_time(CORRECT), None);
+    }
+
+    /// Accepts 24000 as the answer for 2022 day 1 part 1, anything else is too high; answers
+    /// for part 2 are too recent, without telling how long to wait.
+    fn stub_site() -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut form = String::new();
+                request.as_reader().read_to_string(&mut form).unwrap();
+                let page = match (request.url(), form.as_str()) {
+                    ("/2022/day/1/answer", "level=1&answer=24000") => CORRECT,
+                    ("/2022/day/1/answer", form) if form.starts_with("level=2") => {
+                        "<p>You gave an answer too recently.</p>"
+                    }
+                    ("/2022/day/1/answer", _) => TOO_HIGH,
+                    _ => "Not Found",
+                };
+                request.respond(Response::from_string(page)).unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn stub_client(url: &str, cache: &tempfile::TempDir) -> Client {
+        Client::new(Config {
+            session: Some("good".to_string()),
+            base_url: Some(url.to_string()),
+            cache_dir: Some(cache.path().to_path_buf()),
+        })
+    }
+
+    #[test]
+    fn records_outcomes_in_ledger() {
+        let (url, requests) = stub_site();
+        let cache = tempfile::tempdir().unwrap();
+        let client = stub_client(&url, &cache);
+        let mut ledger = Ledger::default();
+
+        let outcome = submit(&client, &mut ledger, (2022, 1, 1), "24000", false).unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+        assert_eq!(ledger.check(2022, 1, 1, "24000"), Verdict::Pass);
+
+        assert!(matches!(
+            submit(&client, &mut ledger, (2022, 1, 1), "24000", false),
+            Err(SubmitError::AlreadyAccepted(_))
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn respects_wait_and_known_wrong_answers() {
+        let (url, requests) = stub_site();
+        let cache = tempfile::tempdir().unwrap();
+        let client = stub_client(&url, &cache);
+        let mut ledger = Ledger::default();
+
+        let outcome = submit(&client, &mut ledger, (2022, 1, 1), "30000", false).unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+        assert_eq!(ledger.get(2022, 1, 1).unwrap().too_high, Some(30000));
+
+        assert!(matches!(
+            submit(&client, &mut ledger, (2022, 1, 1), "30000", false),
+            Err(SubmitError::KnownWrong(_))
+        ));
+        assert!(matches!(
+            submit(&client, &mut ledger, (2022, 1, 1), "35000", false),
+            Err(SubmitError::KnownWrong(_))
+        ));
+        assert!(matches!(
+            submit(&client, &mut ledger, (2022, 1, 1), "24000", false),
+            Err(SubmitError::Throttled(_))
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn throttles_rate_limited_answers() {
+        let (url, requests) = stub_site();
+        let cache = tempfile::tempdir().unwrap();
+        let client = stub_client(&url, &cache);
+        let mut ledger = Ledger::default();
+
+        let outcome = submit(&client, &mut ledger, (2022, 1, 2), "45000", false).unwrap();
+        assert_eq!(outcome, Outcome::RateLimited(Duration::from_secs(60)));
+        match submit(&client, &mut ledger, (2022, 1, 2), "45000", false) {
+            Err(SubmitError::Throttled(wait)) => assert!(wait.as_secs() > 50),
+            other => panic!("not throttled: {:?}", other),
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+}
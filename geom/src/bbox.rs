@@ -0,0 +1,95 @@
+use std::ops::{Add, Sub};
+
+use crate::{Coord, Vec2, Vec3, Vector};
+
+/// Axis-aligned bounding box with inclusive corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BBox<V> {
+    pub min: V,
+    pub max: V,
+}
+
+impl<V: Vector> BBox<V> {
+    pub fn new(p: V) -> Self {
+        Self { min: p, max: p }
+    }
+
+    /// Smallest box containing every point, `None` for an empty iterator.
+    pub fn from_points<I: IntoIterator<Item = V>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bbox = Self::new(points.next()?);
+        for p in points {
+            bbox.extend(p);
+        }
+        Some(bbox)
+    }
+
+    pub fn extend(&mut self, p: V) {
+        self.min = self.min.min(p);
+        self.max = self.max.max(p);
+    }
+
+    pub fn contains(&self, p: V) -> bool {
+        self.min.all_le(p) && p.all_le(self.max)
+    }
+}
+
+impl<V: Vector + Add<Output = V> + Sub<Output = V>> BBox<V> {
+    /// Box enlarged by `margin` on every side.
+    pub fn grow(&self, margin: V) -> Self {
+        Self {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+}
+
+impl<T: Coord> BBox<Vec2<T>> {
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+}
+
+impl<T: Coord> BBox<Vec3<T>> {
+    pub fn volume(&self) -> T {
+        let size = self.max - self.min + Vec3::splat(T::ONE);
+        size.x * size.y * size.z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grows_to_contain_points() {
+        assert_eq!(BBox::<Vec2<i32>>::from_points([]), None);
+        let mut bbox = BBox::from_points([(2, 3), (-1, 5), (4, 4)].map(Vec2::from)).unwrap();
+        assert_eq!((bbox.min, bbox.max), (Vec2::new(-1, 3), Vec2::new(4, 5)));
+        assert_eq!((bbox.width(), bbox.height(), bbox.area()), (6, 3, 18));
+        assert!(bbox.contains(Vec2::new(0, 4)) && bbox.contains(Vec2::new(4, 3)));
+        assert!(!bbox.contains(Vec2::new(5, 4)) && !bbox.contains(Vec2::new(0, 2)));
+
+        bbox.extend(Vec2::new(0, 0));
+        assert_eq!((bbox.min, bbox.max), (Vec2::new(-1, 0), Vec2::new(4, 5)));
+        let grown = bbox.grow(Vec2::new(1, 2));
+        assert_eq!((grown.min, grown.max), (Vec2::new(-2, -2), Vec2::new(5, 7)));
+        assert_eq!(BBox::new(Vec2::new(7, 7)).area(), 1);
+    }
+
+    #[test]
+    fn measures_volume() {
+        let bbox = BBox::from_points([Vec3::new(1, 1, 1), Vec3::new(2, 3, 4)]).unwrap();
+        assert_eq!(bbox.volume(), 2 * 3 * 4);
+        assert!(bbox.contains(Vec3::new(2, 2, 2)) && !bbox.contains(Vec3::new(2, 2, 5)));
+        assert_eq!(bbox.grow(Vec3::splat(1)).volume(), 4 * 5 * 6);
+    }
+}
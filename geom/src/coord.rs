@@ -0,0 +1,81 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Signed integer type usable as a vector component.
+pub trait Coord:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    /// `self + rhs`, `None` if it overflows.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// `self - rhs`, `None` if it overflows.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord_ops<T: Coord>(x: T) -> (T, T, T, T) {
+        (x.abs(), x.signum(), T::ZERO.signum(), x + T::ONE)
+    }
+
+    #[test]
+    fn implements_coord_for_signed_integers() {
+        assert_eq!(coord_ops(-5i8), (5, -1, 0, -4));
+        assert_eq!(coord_ops(7i32), (7, 1, 0, 8));
+        assert_eq!(coord_ops(-3isize), (3, -1, 0, -2));
+        assert_eq!(Coord::checked_add(i64::MAX - 1, 1), Some(i64::MAX));
+        assert_eq!(Coord::checked_add(i64::MAX, 1), None);
+        assert_eq!(Coord::checked_add(i16::MIN, -1), None);
+        assert_eq!(Coord::checked_sub(i32::MAX, i32::MIN), None);
+        assert_eq!(Coord::checked_sub(-1i32, i32::MAX), Some(i32::MIN));
+    }
+}
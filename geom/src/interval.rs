@@ -0,0 +1,350 @@
+use crate::Coord;
+
+/// Closed range of integers `start..=end`, never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Coord> Interval<T> {
+    /// The interval `start..=end`, `None` if `end` comes before `start`.
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    pub fn point(x: T) -> Self {
+        Self { start: x, end: x }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// Number of integers in the interval, `None` if it does not fit in `T`.
+    pub fn size(&self) -> Option<T> {
+        self.end.checked_sub(self.start)?.checked_add(T::ONE)
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    /// `true` if every integer of `other` is in `self`.
+    pub fn contains_interval(&self, other: Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Integers in both intervals, `None` if they are disjoint.
+    pub fn intersection(&self, other: Interval<T>) -> Option<Self> {
+        self.overlaps(other).then(|| Self {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+}
+
+// `true` if an interval starting at `start` overlaps or touches one ending at `end`, even
+// when `end` is the largest value
+fn reaches<T: Coord>(end: T, start: T) -> bool {
+    end.checked_add(T::ONE).is_none_or(|next| start <= next)
+}
+
+/// Set of integers kept as sorted, disjoint intervals, no two of them adjacent.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Coord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Merges intervals sorted by their start in a single pass.
+    ///
+    /// Panics if they are not sorted.
+    pub fn from_sorted<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        for interval in intervals {
+            set.push(interval);
+        }
+        set
+    }
+
+    /// Appends an interval starting no earlier than the last one, merging them if they overlap
+    /// or touch.
+    fn push(&mut self, interval: Interval<T>) {
+        if let Some(last) = self.intervals.last_mut() {
+            assert!(
+                last.start <= interval.start,
+                "intervals are not sorted by start"
+            );
+            if reaches(last.end, interval.start) {
+                last.end = last.end.max(interval.end);
+                return;
+            }
+        }
+        self.intervals.push(interval);
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let lo = self
+            .intervals
+            .partition_point(|i| !reaches(i.end, interval.start));
+        let hi = self
+            .intervals
+            .partition_point(|i| reaches(interval.end, i.start));
+        // The intervals in `lo..hi` overlap or touch the new one
+        if lo == hi {
+            self.intervals.insert(lo, interval);
+            return;
+        }
+        let end = interval.end.max(self.intervals[hi - 1].end);
+        let merged = &mut self.intervals[lo];
+        merged.start = merged.start.min(interval.start);
+        merged.end = end;
+        self.intervals.drain(lo + 1..hi);
+    }
+
+    /// Removes every interval, keeping the memory for the next ones.
+    pub fn clear(&mut self) {
+        self.intervals.clear();
+    }
+
+    /// The intervals of the set, sorted.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end < x);
+        self.intervals.get(i).is_some_and(|i| i.start <= x)
+    }
+
+    /// Number of integers in the set, `None` if it does not fit in `T`.
+    pub fn len(&self) -> Option<T> {
+        self.intervals
+            .iter()
+            .try_fold(T::ZERO, |n, i| n.checked_add(i.size()?))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The smallest integer of `interval` missing from the set.
+    pub fn first_missing(&self, interval: Interval<T>) -> Option<T> {
+        let i = self.intervals.partition_point(|i| i.end < interval.start);
+        match self.intervals.get(i) {
+            // Intervals never touch, so the integer after this one is missing
+            Some(covering) if covering.start <= interval.start => covering
+                .end
+                .checked_add(T::ONE)
+                .filter(|&x| x <= interval.end),
+            _ => Some(interval.start),
+        }
+    }
+
+    /// The missing integers between the first and the last interval, one interval per hole.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.windows(2).map(|w| Interval {
+            start: w[0].end + T::ONE,
+            end: w[1].start - T::ONE,
+        })
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut lhs = self.intervals.iter().peekable();
+        let mut rhs = other.intervals.iter().peekable();
+        let mut union = Self::new();
+        loop {
+            let next = match (lhs.peek(), rhs.peek()) {
+                (Some(l), Some(r)) if r.start < l.start => rhs.next(),
+                (Some(_), _) => lhs.next(),
+                (None, _) => rhs.next(),
+            };
+            match next {
+                Some(&interval) => union.push(interval),
+                None => return union,
+            }
+        }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (lhs, rhs) = (&self.intervals, &other.intervals);
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < lhs.len() && j < rhs.len() {
+            intervals.extend(lhs[i].intersection(rhs[j]));
+            if lhs[i].end < rhs[j].end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        // Pieces of disjoint, non adjacent intervals are never adjacent either
+        Self { intervals }
+    }
+
+    /// Integers of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let rhs = &other.intervals;
+        let mut intervals = Vec::new();
+        let mut j = 0;
+        for interval in &self.intervals {
+            while j < rhs.len() && rhs[j].end < interval.start {
+                j += 1;
+            }
+            // The first integer not removed yet, `None` once the largest value is removed
+            let mut start = Some(interval.start);
+            // The last interval removed may reach into the next one, so `j` stays on it
+            for removed in rhs[j..].iter().take_while(|r| r.start <= interval.end) {
+                if let Some(start) = start.filter(|&start| start < removed.start) {
+                    intervals.push(Interval {
+                        start,
+                        end: removed.start - T::ONE,
+                    });
+                }
+                start = removed.end.checked_add(T::ONE);
+            }
+            intervals.extend(start.and_then(|start| Interval::new(start, interval.end)));
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Coord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl<T: Coord> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, intervals: I) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Coord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut intervals: Vec<_> = intervals.into_iter().collect();
+        intervals.sort_unstable_by_key(|i| i.start);
+        Self::from_sorted(intervals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: i32, end: i32) -> Interval<i32> {
+        Interval::new(start, end).unwrap()
+    }
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| interval(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn interval_set_operations() {
+        let a = set(&[(1, 3), (4, 6), (10, 12), (2, 2)]);
+        let b = set(&[(0, 1), (5, 10), (14, 20)]);
+        assert_eq!(a, set(&[(1, 6), (10, 12)]));
+        assert!(a.contains(6) && !a.contains(7) && !a.contains(13));
+
+        assert_eq!(a.union(&b), set(&[(0, 12), (14, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(1, 1), (5, 6), (10, 10)]));
+        assert_eq!(a.difference(&b), set(&[(2, 4), (11, 12)]));
+        assert_eq!(b.difference(&a), set(&[(0, 0), (7, 9), (14, 20)]));
+        assert_eq!(
+            b.gaps().collect::<Vec<_>>(),
+            [interval(2, 4), interval(11, 13)]
+        );
+    }
+
+    #[test]
+    fn inserts_intervals() {
+        let mut c = IntervalSet::from_sorted([interval(1, 2), interval(3, 4), interval(8, 9)]);
+        assert_eq!(c, set(&[(1, 4), (8, 9)]));
+        c.insert(interval(5, 6));
+        c.insert(Interval::point(20));
+        assert_eq!(c, set(&[(1, 6), (8, 9), (20, 20)]));
+        c.insert(interval(0, 10));
+        assert_eq!(c.intervals(), [interval(0, 10), Interval::point(20)]);
+        assert_eq!(c.len(), Some(12));
+
+        c.clear();
+        assert!(c.is_empty());
+        c.extend([interval(4, 5), interval(1, 2), interval(3, 3)]);
+        assert_eq!(c.intervals(), [interval(1, 5)]);
+    }
+
+    #[test]
+    fn finds_the_first_missing_integer() {
+        let a = set(&[(1, 3), (6, 8)]);
+        assert_eq!(a.first_missing(interval(0, 10)), Some(0));
+        assert_eq!(a.first_missing(interval(2, 10)), Some(4));
+        assert_eq!(a.first_missing(interval(4, 5)), Some(4));
+        assert_eq!(a.first_missing(interval(6, 8)), None);
+        assert_eq!(a.first_missing(interval(7, 9)), Some(9));
+        assert_eq!(
+            set(&[(0, i32::MAX)]).first_missing(interval(5, i32::MAX)),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_empty_intervals() {
+        assert_eq!(Interval::new(3, 2), None);
+        assert_eq!(Interval::new(2, 2), Some(Interval::point(2)));
+        let a = interval(-2, 5);
+        assert_eq!((a.start(), a.end(), a.size()), (-2, 5, Some(8)));
+    }
+
+    #[test]
+    fn handles_the_largest_values() {
+        let (min, max) = (i32::MIN, i32::MAX);
+        let mut a = set(&[(max - 1, max), (min, min + 1), (0, max - 3)]);
+        assert_eq!(a, set(&[(min, min + 1), (0, max - 3), (max - 1, max)]));
+        a.insert(Interval::point(max - 2));
+        assert_eq!(a, set(&[(min, min + 1), (0, max)]));
+        assert!(a.contains(max) && !a.contains(min + 2));
+        assert_eq!(
+            a.union(&set(&[(max, max), (min + 2, -1)])),
+            set(&[(min, max)])
+        );
+
+        let b = set(&[(min, 0), (max, max)]);
+        assert_eq!(a.difference(&b), set(&[(1, max - 1)]));
+        assert_eq!(b.difference(&a), set(&[(min + 2, -1)]));
+        assert_eq!(
+            set(&[(max - 5, max)]).difference(&b),
+            set(&[(max - 5, max - 1)])
+        );
+        assert_eq!(b.gaps().collect::<Vec<_>>(), [interval(1, max - 1)]);
+
+        assert_eq!(interval(min, max).size(), None);
+        assert_eq!(interval(0, max).size(), None);
+        assert_eq!(interval(1, max).size(), Some(max));
+        assert_eq!(set(&[(min, -1), (1, max)]).len(), None);
+        assert_eq!(set(&[(-5, -1), (1, max - 5)]).len(), Some(max));
+    }
+}
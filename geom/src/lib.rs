@@ -0,0 +1,21 @@
+mod bbox;
+mod coord;
+mod interval;
+mod vec2;
+mod vec3;
+
+pub use bbox::BBox;
+pub use coord::Coord;
+pub use interval::{Interval, IntervalSet};
+pub use vec2::Vec2;
+pub use vec3::Vec3;
+
+/// Operations shared by all vector types, used by [`BBox`].
+pub trait Vector: Copy + PartialEq {
+    /// Component-wise minimum.
+    fn min(self, other: Self) -> Self;
+    /// Component-wise maximum.
+    fn max(self, other: Self) -> Self;
+    /// `true` if every component of `self` is `<=` the matching component of `other`.
+    fn all_le(self, other: Self) -> bool;
+}
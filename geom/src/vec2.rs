@@ -0,0 +1,257 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{Coord, Vector};
+
+/// 2D integer vector in screen coordinates: `x` grows to the east and `y`
+/// grows to the south, so north is `(0, -1)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(T::ZERO, T::ZERO)
+    }
+
+    pub fn north() -> Self {
+        Self::new(T::ZERO, -T::ONE)
+    }
+
+    pub fn south() -> Self {
+        Self::new(T::ZERO, T::ONE)
+    }
+
+    pub fn east() -> Self {
+        Self::new(T::ONE, T::ZERO)
+    }
+
+    pub fn west() -> Self {
+        Self::new(-T::ONE, T::ZERO)
+    }
+
+    /// Unit steps to the orthogonal neighbours: north, east, south, west.
+    pub fn dirs4() -> [Self; 4] {
+        [Self::north(), Self::east(), Self::south(), Self::west()]
+    }
+
+    /// Unit steps to all eight neighbours, clockwise starting from north.
+    pub fn dirs8() -> [Self; 8] {
+        let (n, e, s, w) = (Self::north(), Self::east(), Self::south(), Self::west());
+        [n, n + e, e, s + e, s, s + w, w, n + w]
+    }
+
+    pub fn neighbors4(self) -> [Self; 4] {
+        Self::dirs4().map(|d| self + d)
+    }
+
+    pub fn neighbors8(self) -> [Self; 8] {
+        Self::dirs8().map(|d| self + d)
+    }
+
+    /// Rotates by 90 degrees clockwise (east becomes south).
+    pub fn rot_cw(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates by 90 degrees counterclockwise (east becomes north).
+    pub fn rot_ccw(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn rot180(self) -> Self {
+        -self
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Component-wise signum, e.g. the unit step from `a` towards `b` along
+    /// a straight line is `(b - a).signum()`.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x + d.y
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x.max(d.y)
+    }
+}
+
+impl<T: Coord> Vector for Vec2<T> {
+    fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    fn all_le(self, other: Self) -> bool {
+        self.x <= other.x && self.y <= other.y
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl<T: Coord> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Coord> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coord> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Coord> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coord> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Coord> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type V = Vec2<i32>;
+
+    #[test]
+    fn directions_and_neighbours() {
+        assert_eq!(V::zero(), V::new(0, 0));
+        assert_eq!(V::dirs4(), [(0, -1), (1, 0), (0, 1), (-1, 0)].map(V::from));
+        assert_eq!(
+            V::dirs8(),
+            [
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1)
+            ]
+            .map(V::from)
+        );
+        let p = V::new(3, 5);
+        assert_eq!(
+            p.neighbors4(),
+            [(3, 4), (4, 5), (3, 6), (2, 5)].map(V::from)
+        );
+        assert_eq!(
+            p.neighbors8(),
+            [
+                (3, 4),
+                (4, 4),
+                (4, 5),
+                (4, 6),
+                (3, 6),
+                (2, 6),
+                (2, 5),
+                (2, 4)
+            ]
+            .map(V::from)
+        );
+    }
+
+    #[test]
+    fn rotations() {
+        assert_eq!(V::east().rot_cw(), V::south());
+        assert_eq!(V::south().rot_cw(), V::west());
+        assert_eq!(V::east().rot_ccw(), V::north());
+        assert_eq!(V::north().rot_ccw(), V::west());
+        let p = V::new(2, -7);
+        assert_eq!(p.rot_cw().rot_ccw(), p);
+        assert_eq!(p.rot_cw().rot_cw(), p.rot180());
+        assert_eq!(p.rot180(), V::new(-2, 7));
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (V::new(1, -2), V::new(-3, 4));
+        assert_eq!((a - b).abs(), V::new(4, 6));
+        assert_eq!((b - a).signum(), V::new(-1, 1));
+        assert_eq!(V::new(0, 5).signum(), V::south());
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut p = V::new(1, 2);
+        p += V::new(3, 4);
+        assert_eq!(p, V::new(4, 6));
+        p -= V::new(1, 1);
+        assert_eq!(p, V::new(3, 5));
+        p *= 2;
+        assert_eq!(p, V::new(6, 10));
+        assert_eq!(-p + p * 3 - V::new(1, 1), V::new(11, 19));
+        assert_eq!(Vector::min(V::new(1, 5), V::new(3, 2)), V::new(1, 2));
+        assert_eq!(Vector::max(V::new(1, 5), V::new(3, 2)), V::new(3, 5));
+        assert!(V::new(1, 2).all_le(V::new(1, 3)) && !V::new(1, 2).all_le(V::new(0, 3)));
+        assert_eq!(V::new(-1, 2).to_string(), "(-1,2)");
+    }
+}
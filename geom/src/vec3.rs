@@ -0,0 +1,214 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{Coord, Vector};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn splat(v: T) -> Self {
+        Self::new(v, v, v)
+    }
+
+    /// Unit steps to the six face-adjacent neighbours.
+    pub fn dirs6() -> [Self; 6] {
+        let (o, z) = (T::ONE, T::ZERO);
+        [
+            Self::new(-o, z, z),
+            Self::new(o, z, z),
+            Self::new(z, -o, z),
+            Self::new(z, o, z),
+            Self::new(z, z, -o),
+            Self::new(z, z, o),
+        ]
+    }
+
+    pub fn neighbors6(self) -> [Self; 6] {
+        Self::dirs6().map(|d| self + d)
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x + d.y + d.z
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x.max(d.y).max(d.z)
+    }
+}
+
+impl<T: Coord> Vector for Vec3<T> {
+    fn min(self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    fn all_le(self, other: Self) -> bool {
+        self.x <= other.x && self.y <= other.y && self.z <= other.z
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{},{})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Coord> Add for Vec3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coord> AddAssign for Vec3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: Coord> Sub for Vec3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Coord> SubAssign for Vec3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T: Coord> Neg for Vec3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Coord> Mul<T> for Vec3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Coord> MulAssign<T> for Vec3<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type V = Vec3<i32>;
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(V::zero(), V::splat(0));
+        let p = V::new(1, 2, 3);
+        let neighbors = p.neighbors6();
+        assert_eq!(
+            neighbors,
+            [
+                (0, 2, 3),
+                (2, 2, 3),
+                (1, 1, 3),
+                (1, 3, 3),
+                (1, 2, 2),
+                (1, 2, 4)
+            ]
+            .map(V::from)
+        );
+        assert!(neighbors.iter().all(|&n| n.manhattan(p) == 1));
+        assert!(V::dirs6()
+            .iter()
+            .all(|&d| d.abs().manhattan(V::zero()) == 1));
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (V::new(1, -2, 0), V::new(-3, 4, 2));
+        assert_eq!((a - b).abs(), V::new(4, 6, 2));
+        assert_eq!((b - a).signum(), V::new(-1, 1, 1));
+        assert_eq!(V::new(0, 0, -5).signum(), V::new(0, 0, -1));
+        assert_eq!(a.manhattan(b), 12);
+        assert_eq!(a.chebyshev(b), 6);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut p = V::new(1, 2, 3);
+        p += V::splat(1);
+        assert_eq!(p, V::new(2, 3, 4));
+        p -= V::new(1, 0, 1);
+        assert_eq!(p, V::new(1, 3, 3));
+        p *= 2;
+        assert_eq!(p, V::new(2, 6, 6));
+        assert_eq!(-p + p * 3 - V::splat(1), V::new(3, 11, 11));
+        assert_eq!(
+            Vector::min(V::new(1, 5, 0), V::new(3, 2, 0)),
+            V::new(1, 2, 0)
+        );
+        assert_eq!(
+            Vector::max(V::new(1, 5, 0), V::new(3, 2, 1)),
+            V::new(3, 5, 1)
+        );
+        assert!(V::splat(1).all_le(V::new(1, 2, 3)) && !V::splat(2).all_le(V::new(1, 2, 3)));
+        assert_eq!(V::from((-1, 2, 0)).to_string(), "(-1,2,0)");
+    }
+}
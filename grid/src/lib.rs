@@ -0,0 +1,310 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use geom::Vec2;
+
+/// Cell position: `x` is the column and `y` is the row.
+pub type Pos = Vec2<i32>;
+
+/// Dense row-major 2D container.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from text, one row per line, mapping every char with `f`.
+    /// Lines shorter than the longest one are padded with `f(' ')`; empty lines at the end
+    /// are ignored.
+    pub fn parse<F: FnMut(char) -> T>(text: &str, mut f: F) -> Self {
+        let lines: Vec<&str> = text.trim_end_matches(['\r', '\n']).lines().collect();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            let row_start = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            if cells.len() - row_start < width {
+                let pad = f(' ');
+                cells.resize(row_start + width, pad);
+            }
+        }
+
+        Self {
+            width,
+            height: lines.len(),
+            cells,
+        }
+    }
+
+    /// Appends `n` rows filled with `fill`.
+    pub fn extend_rows(&mut self, n: usize, fill: T) {
+        self.height += n;
+        self.cells.resize(self.width * self.height, fill);
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            cells.extend(self.column(x).cloned());
+        }
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            cells.extend(self.column(x).rev().cloned());
+        }
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in (0..self.width).rev() {
+            cells.extend(self.column(x).cloned());
+        }
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, p: Pos) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn index_of(&self, p: Pos) -> Option<usize> {
+        if self.in_bounds(p) {
+            Some((p.y as usize) * self.width + (p.x as usize))
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, p: Pos) -> Option<&T> {
+        self.index_of(p).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, p: Pos) -> Option<&mut T> {
+        self.index_of(p).map(|idx| &mut self.cells[idx])
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| Pos::new((idx % width) as i32, (idx / width) as i32))
+    }
+
+    /// All cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {} is out of grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// In-bounds orthogonal neighbours of `p`.
+    pub fn neighbors4(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+        p.neighbors4().into_iter().filter(|n| self.in_bounds(*n))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `p`.
+    pub fn neighbors8(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+        p.neighbors8().into_iter().filter(|n| self.in_bounds(*n))
+    }
+
+    /// Cells met walking from `from` (exclusive) in direction `dir` up to the grid border.
+    pub fn ray(&self, from: Pos, dir: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        let mut p = from;
+        std::iter::from_fn(move || {
+            p += dir;
+            self.get(p).map(|cell| (p, cell))
+        })
+    }
+
+    /// Position of the first cell (in row-major order) matching `pred`.
+    pub fn position<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<Pos> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(p, _)| p)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Position of the first cell (in row-major order) equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Pos> {
+        self.position(|cell| cell == value)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Pos) -> &Self::Output {
+        match self.get(p) {
+            Some(cell) => cell,
+            None => panic!("{} is out of grid {}x{}", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, p: Pos) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(p) {
+            Some(cell) => cell,
+            None => panic!("{} is out of grid {}x{}", p, width, height),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn parses_ragged_text() {
+        let grid = Grid::parse("ab\nabcd\r\n\r\n", |c| c);
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        assert_eq!(grid.to_string(), "ab  \nabcd\n");
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(
+            (grid.rotate_cw().width(), grid.rotate_cw().height()),
+            (2, 3)
+        );
+    }
+
+    #[test]
+    fn reads_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.column(0).rev().collect::<String>(), "da");
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 3 is out of grid")]
+    fn rejects_columns_out_of_grid() {
+        let _ = grid().column(3);
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let mut grid = grid();
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, 2)), None);
+        assert_eq!(grid.get(Pos::new(-1, 0)), None);
+        *grid.get_mut(Pos::new(1, 0)).unwrap() = 'x';
+        assert_eq!(grid[Pos::new(1, 0)], 'x');
+        assert!(grid.get_mut(Pos::new(0, -1)).is_none());
+        assert_eq!(grid.neighbors4(Pos::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8(Pos::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn walks_rays_to_the_border() {
+        let grid = grid();
+        let ray = |from: Pos, dir: Pos| grid.ray(from, dir).map(|(_, &c)| c).collect::<String>();
+        assert_eq!(ray(Pos::new(0, 0), Pos::east()), "bc");
+        assert_eq!(ray(Pos::new(2, 1), Pos::west()), "ed");
+        assert_eq!(ray(Pos::new(0, 0), Pos::new(1, 1)), "e");
+        assert_eq!(ray(Pos::new(1, 0), Pos::north()), "");
+        assert_eq!(
+            grid.ray(Pos::new(1, 0), Pos::south()).collect::<Vec<_>>(),
+            [(Pos::new(1, 1), &'e')]
+        );
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid = grid();
+        assert_eq!(grid.find(&'e'), Some(Pos::new(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.position(|&c| c > 'c'), Some(Pos::new(0, 1)));
+        assert_eq!(
+            grid.map(|&c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "(3,0) is out of grid 3x2")]
+    fn rejects_indices_out_of_grid() {
+        let _ = grid()[Pos::new(3, 0)];
+    }
+}